@@ -1,7 +1,81 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use uuid::Uuid;
 
+/// A fluent builder for presentation chapters
+pub mod builder;
+
+pub use builder::*;
+
+/// Navigation through the slides of a presentation
+pub mod cursor;
+
+pub use cursor::*;
+
+/// Structural comparison of presentation chapters
+pub mod diff;
+
+/// Exporters for presentation chapters
+pub mod export;
+
+/// Importers creating presentation chapters from other formats
+pub mod import;
+
+/// Backend-agnostic render commands for slide content
+pub mod render;
+
+pub use render::*;
+
+/// YAML serialization of presentation chapters
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+/// Unicode normalization of slide text
+#[cfg(feature = "unicode")]
+pub mod unicode;
+
+/// Compact binary serialization of presentation chapters
+#[cfg(feature = "bincode")]
+pub mod bincode;
+
+/// MessagePack serialization of presentation chapters
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+
+/// Regular expression replacement of slide text
+#[cfg(feature = "regex")]
+pub mod replace;
+
+/// Deserialization of internally tagged and legacy slide content
+#[cfg(feature = "tagged-content")]
+mod tagged;
+
+/// JSON Schema generation for the slide types
+#[cfg(feature = "schema")]
+pub mod schema;
+
+/// A Presentation represents a whole event (e.g. a worship service) as an ordered sequence of chapters.
+/// It can be serialized as a single document, so an entire service can be persisted in one file.
+///
+/// # Generic Parameters
+/// T: The linked entity (Song, BibleVerse, etc.)
+/// M: The media type (SongFile, PathBuf, etc.)
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct Presentation<T, M> {
+    /// The optional title of the presentation (e.g. the name of the service).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The chapters of the presentation in the order they are presented.
+    pub chapters: Vec<PresentationChapter<T, M>>,
+}
+
 /// A Presentation Chapter represents a logical grouping of slides within a presentation.
 /// It is used to organize the content into sections or chapters.
 ///
@@ -9,18 +83,39 @@ use uuid::Uuid;
 /// T: The linked entity (Song, BibleVerse, etc.)
 /// M: The media type (SongFile, PathBuf, etc.)
 #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
 pub struct PresentationChapter<T, M> {
     /// The slides within this chapter.
     pub slides: Vec<Slide<M>>,
 
     /// The linked entity of the chapter, most likely connected to content where it is derived from.
     pub linked_entity: LinkedEntity<T, M>,
+
+    /// The version of the format the chapter was written with.
+    /// A missing version does not default to [`CURRENT_SCHEMA_VERSION`] but to
+    /// [`UNVERSIONED_SCHEMA_VERSION`], so chapters written before the format was versioned can be
+    /// told apart. Use [`PresentationChapter::migrate`] to upgrade chapters of older versions.
+    #[serde(default = "unversioned_schema_version")]
+    pub schema_version: u32,
+}
+
+/// The current version of the presentation chapter format
+pub const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// The version of chapters written before the format was versioned
+pub const UNVERSIONED_SCHEMA_VERSION: u32 = 0;
+
+fn unversioned_schema_version() -> u32 {
+    UNVERSIONED_SCHEMA_VERSION
 }
 
 /// The linked entity defines a reference to a specific entity from which the presentation is derived.
 /// It is most likely a song or Bible verse.
 /// This crate just provides an abstract definition, the implementation is left to other Cantara crates.
 #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
 pub enum LinkedEntity<T, M> {
     /// A specific source file
     Source(T),
@@ -35,238 +130,5728 @@ pub enum LinkedEntity<T, M> {
 /// A slide is a struct which represents content for a single slide in a presentation.
 /// Every slide has a unique identifier (UUID v7) and can contain various types of content.
 /// The UUID is generated when the slide is created and can be used to uniquely identify the slide in a presentation.
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+/// Equality and hashing include the UUID, use [`Slide::key`] to deduplicate slides by their content.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
 pub struct Slide<M> {
     /// The unique identifier of the slide (UUID v7)
     /// It will be generated when the slide is created and can be used to uniquely identify the slide in a presentation.
     /// It is not meant to be modified after creation, therefore the field is private.
+    #[cfg_attr(feature = "bincode", bincode(with_serde))]
     uuid: Uuid,
 
+    /// The optional stable identifier of the slide, used to match slides across applications.
+    /// Unlike the UUID it is kept when a slide is deserialized into another client and can be
+    /// assigned by the application.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
     /// The content of the slide
     pub slide_content: SlideContent,
 
     /// The optional linked file of the slide
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub linked_file: Option<M>,
+
+    /// The optional transition used when this slide is shown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transition: Option<Transition>,
+
+    /// The optional duration in milliseconds after which the slide advances automatically
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_millis: Option<u32>,
+
+    /// Optional private notes for the presenter, they are never projected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+
+    /// The optional background of the slide, replacing the default background
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<Background>,
+
+    /// Whether the slide repeats an earlier slide (e.g. a chorus), so it can be shown differently
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_repeat: bool,
 }
 
 // --- Implementation Blocks (Where the bounds actually matter) ---
-impl<T, M> PresentationChapter<T, M>
+impl<T, M> Presentation<T, M>
 where
     T: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
     M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
 {
-    pub fn new(slides: Vec<Slide<M>>, linked_entity: LinkedEntity<T, M>) -> Self {
-        Self {
-            slides,
-            linked_entity,
+    pub fn new(chapters: Vec<PresentationChapter<T, M>>, title: Option<String>) -> Self {
+        Self { title, chapters }
+    }
+
+    pub fn push_chapter(&mut self, chapter: PresentationChapter<T, M>) {
+        self.chapters.push(chapter);
+    }
+
+    /// Flattens all chapters into one slide stream, inserting a copy of `separator` between chapters.
+    /// No separator is inserted before the first or after the last chapter.
+    /// Every inserted copy gets its own UUID, so the slides stay uniquely identifiable.
+    pub fn flatten_with_separators(&self, separator: Slide<M>) -> Vec<Slide<M>> {
+        let mut slides = Vec::with_capacity(self.total_slide_count());
+        for (index, chapter) in self.chapters.iter().enumerate() {
+            if index > 0 {
+                slides.push(separator.duplicate());
+            }
+            slides.extend(chapter.slides.iter().cloned());
         }
+        slides
+    }
+
+    /// Returns one title per chapter, e.g. for an overview slide.
+    /// The title is taken from the first title slide of the chapter, falling back
+    /// to the title of the linked entity or "Untitled".
+    pub fn table_of_contents(&self) -> Vec<String> {
+        self.chapters
+            .iter()
+            .map(|chapter| {
+                chapter
+                    .display_title()
+                    .unwrap_or_else(|| "Untitled".to_string())
+            })
+            .collect()
+    }
+
+    /// Returns the number of slides across all chapters of the presentation
+    pub fn total_slide_count(&self) -> usize {
+        self.chapters.iter().map(|c| c.len()).sum()
+    }
+
+    /// Returns a cursor on the first slide of the presentation, see [`Cursor`]
+    pub fn cursor(&self) -> Cursor<'_, T, M> {
+        Cursor::new(self)
+    }
+
+    /// Iterates over the slides of all chapters in order, together with the index of their
+    /// chapter and their index within the chapter
+    pub fn iter_slides(&self) -> impl Iterator<Item = (usize, usize, &Slide<M>)> {
+        self.chapters
+            .iter()
+            .enumerate()
+            .flat_map(|(chapter_index, chapter)| {
+                chapter
+                    .slides
+                    .iter()
+                    .enumerate()
+                    .map(move |(slide_index, slide)| (chapter_index, slide_index, slide))
+            })
+    }
+
+    /// Concatenates the slides of all chapters in order into one chapter linked to the given entity,
+    /// e.g. for simple linear playback. The slides keep their UUIDs.
+    pub fn into_single_chapter(
+        self,
+        linked_entity: LinkedEntity<T, M>,
+    ) -> PresentationChapter<T, M> {
+        PresentationChapter::from_slides(
+            self.chapters.into_iter().flat_map(|chapter| chapter.slides),
+            linked_entity,
+        )
     }
 }
 
-impl<M> Slide<M>
+impl<T, M> PresentationChapter<T, M>
 where
+    T: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
     M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
 {
-    pub fn new_empty_slide(black_background: bool) -> Self {
+    pub fn new(slides: Vec<Slide<M>>, linked_entity: LinkedEntity<T, M>) -> Self {
         Self {
-            uuid: Uuid::now_v7(),
-            slide_content: SlideContent::Empty(EmptySlide { black_background }),
-            linked_file: None,
+            slides,
+            linked_entity,
+            schema_version: CURRENT_SCHEMA_VERSION,
         }
     }
 
-    pub fn new_content_slide(
-        main_text: String,
-        spoiler_text: Option<String>,
-        meta_text: Option<String>,
-    ) -> Self {
-        Self {
-            uuid: Uuid::now_v7(),
-            slide_content: SlideContent::SingleLanguageMainContent(
-                SingleLanguageMainContentSlide::new(
-                    main_text.trim().to_string(),
-                    spoiler_text.map(|s| s.trim().to_string()),
-                    meta_text.map(|s| s.trim().to_string()),
-                ),
-            ),
-            linked_file: None,
+    /// Serializes the chapter to indented, human-readable JSON
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serializes the chapter to JSON without any whitespace
+    pub fn to_json_compact(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a chapter from JSON
+    pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Deserializes a chapter from JSON, rejecting unknown fields (e.g. typos like `mian_text`).
+    /// [`PresentationChapter::from_json_str`] silently ignores unknown fields instead.
+    pub fn from_json_str_strict(json: &str) -> Result<Self, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let mut unknown_fields = Vec::new();
+        let chapter: Self = serde_ignored::deserialize(&mut deserializer, |path| {
+            unknown_fields.push(path.to_string())
+        })?;
+        deserializer.end()?;
+        match unknown_fields.first() {
+            Some(field) => Err(serde::de::Error::custom(format!(
+                "unknown field `{}`",
+                field
+            ))),
+            None => Ok(chapter),
         }
     }
 
-    pub fn new_title_slide(title_text: String, meta_text: Option<String>) -> Self {
-        Self {
-            uuid: Uuid::now_v7(),
-            slide_content: SlideContent::Title(TitleSlide {
-                title_text: title_text.trim().to_string(),
-                meta_text: meta_text.map(|s| s.trim().to_string()),
-            }),
-            linked_file: None,
+    /// Deserializes a chapter from JSON like [`PresentationChapter::from_json_str`], but
+    /// reports failures as a [`SlideError`] with the location of the problem in the input
+    pub fn load_from_json(json: &str) -> Result<Self, SlideError> {
+        serde_json::from_str(json).map_err(|error| SlideError::from_json(error, json))
+    }
+
+    /// Upgrades a chapter written with an older schema version to the current format
+    pub fn migrate(&mut self) {
+        // Version 1 is the first versioned format, older chapters already have the same shape
+        if self.schema_version < CURRENT_SCHEMA_VERSION {
+            self.schema_version = CURRENT_SCHEMA_VERSION;
         }
     }
 
-    pub fn with_media(mut self, media: M) -> Self {
-        self.linked_file = Some(media);
-        self
+    /// Creates a chapter from an iterator of slides, e.g. from lazily generated slides
+    pub fn from_slides<I>(slides: I, linked_entity: LinkedEntity<T, M>) -> Self
+    where
+        I: IntoIterator<Item = Slide<M>>,
+    {
+        Self::new(slides.into_iter().collect(), linked_entity)
     }
 
-    pub fn has_spoiler(&self) -> bool {
-        match &self.slide_content {
-            SlideContent::SingleLanguageMainContent(s) => s.spoiler_text.is_some(),
-            SlideContent::MultiLanguageMainContent(s) => !s.spoiler_text_vector.is_empty(),
-            _ => false,
+    /// Merges two chapters by concatenating their slides.
+    /// This only succeeds when both chapters are linked to the same entity.
+    pub fn merge(mut self, other: Self) -> Result<Self, MergeError> {
+        if self.linked_entity != other.linked_entity {
+            return Err(MergeError::EntityMismatch);
         }
+        self.slides.extend(other.slides);
+        Ok(self)
     }
 
-    pub fn has_meta_text(&self) -> bool {
-        match &self.slide_content {
-            SlideContent::SingleLanguageMainContent(s) => s.meta_text.is_some(),
-            SlideContent::Title(s) => s.meta_text.is_some(),
-            SlideContent::MultiLanguageMainContent(s) => s.meta_text.is_some(),
-            _ => false,
+    /// Appends copies of the slides of another chapter, keeping the linked entity of this chapter.
+    /// Unlike [`merge`](Self::merge), the linked entities are not compared, so chapters derived
+    /// from different entities (e.g. a song and a separate refrain) can be combined.
+    /// Every copy gets its own UUID and no id, so the same slides can be appended more than once.
+    pub fn append_slides_from(&mut self, other: &Self) {
+        self.slides
+            .extend(other.slides.iter().map(Slide::duplicate));
+    }
+
+    /// Validates all slides of the chapter and returns the first error together with its slide index
+    pub fn validate(&self) -> Result<(), ChapterValidationError> {
+        self.slides
+            .iter()
+            .enumerate()
+            .try_for_each(|(slide_index, slide)| {
+                slide
+                    .validate()
+                    .map_err(|error| ChapterValidationError { slide_index, error })
+            })
+    }
+
+    /// Returns the summed display duration of all slides in milliseconds,
+    /// or `None` if any slide has no duration.
+    pub fn total_duration_millis(&self) -> Option<u32> {
+        self.slides.iter().try_fold(0u32, |total, slide| {
+            slide
+                .duration_millis
+                .map(|duration| total.saturating_add(duration))
+        })
+    }
+
+    /// Exports the chapter as plain text, e.g. for printed handouts.
+    /// Slides are separated by blank lines, empty slides are skipped and
+    /// meta text is put in parentheses after the text of its slide.
+    pub fn to_plain_text(&self) -> String {
+        self.slides
+            .iter()
+            .filter(|slide| !slide.is_empty())
+            .map(|slide| match slide.slide_content.meta_text() {
+                Some(meta_text) => format!("{}\n({})", slide, meta_text),
+                None => slide.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Exports the chapter as Markdown.
+    /// Title slides become headings, content slides paragraphs, meta text italic lines,
+    /// pictures images, countdowns their bold target time and empty slides horizontal rules.
+    /// Lines of a text end with hard line breaks and characters with a meaning in Markdown
+    /// are escaped, so the text is shown as it is.
+    pub fn to_markdown(&self) -> String {
+        let mut blocks: Vec<String> = Vec::new();
+        for slide in &self.slides {
+            match &slide.slide_content {
+                SlideContent::SingleLanguageMainContent(s) => {
+                    blocks.push(markdown_text(&s.main_text))
+                }
+                SlideContent::Title(s) => blocks.push(format!(
+                    "# {}",
+                    escape_markdown(&s.title_text.lines().collect::<Vec<_>>().join(" "))
+                )),
+                SlideContent::MultiLanguageMainContent(s) => {
+                    blocks.extend(s.main_text_list.iter().map(|text| markdown_text(text)))
+                }
+                SlideContent::SimplePicture(s) => blocks.push(format!(
+                    "![{}]({})",
+                    escape_markdown(s.alt_text.as_deref().unwrap_or_default()),
+                    markdown_destination(&s.picture_path)
+                )),
+                SlideContent::Empty(_) => blocks.push("---".to_string()),
+                SlideContent::Video(s) => {
+                    blocks.push(format!("[video]({})", markdown_destination(&s.video_path)))
+                }
+                SlideContent::Countdown(s) => {
+                    blocks.push(format!(
+                        "**{}:{:02}**",
+                        s.target_seconds / 60,
+                        s.target_seconds % 60
+                    ));
+                    if let Some(message) = &s.message {
+                        blocks.push(markdown_text(message));
+                    }
+                }
+            }
+            if let Some(meta_text) = slide.slide_content.meta_text() {
+                blocks.push(format!("*{}*", markdown_text(meta_text)));
+            }
+        }
+        blocks.retain(|block| !block.is_empty());
+        let mut markdown = blocks.join("\n\n");
+        if !markdown.is_empty() {
+            markdown.push('\n');
         }
+        markdown
     }
 
-    /// Returns the UUID of the slide (UUID v7)
-    pub fn uuid(&self) -> Uuid {
-        self.uuid
+    /// Transforms the media type of the chapter by applying `f` to the linked file of every slide
+    /// and to a `LinkedEntity::Media` value. The content of the slides is preserved.
+    pub fn map_media<N, F>(self, mut f: F) -> PresentationChapter<T, N>
+    where
+        F: FnMut(M) -> N,
+    {
+        let linked_entity = match self.linked_entity {
+            LinkedEntity::Source(source) => LinkedEntity::Source(source),
+            LinkedEntity::Title(title) => LinkedEntity::Title(title),
+            LinkedEntity::Media(media) => LinkedEntity::Media(f(media)),
+        };
+        PresentationChapter {
+            slides: self
+                .slides
+                .into_iter()
+                .map(|slide| slide.map_media(&mut f))
+                .collect(),
+            linked_entity,
+            schema_version: self.schema_version,
+        }
     }
-}
 
-// --- Content Definitions ---
+    /// Transforms the entity type of the chapter by applying `f` to a `LinkedEntity::Source` value.
+    /// `LinkedEntity::Title` and `LinkedEntity::Media` are kept as they are, so `f` is not called for them.
+    pub fn map_entity<U, F>(self, f: F) -> PresentationChapter<U, M>
+    where
+        F: FnOnce(T) -> U,
+    {
+        let linked_entity = match self.linked_entity {
+            LinkedEntity::Source(source) => LinkedEntity::Source(f(source)),
+            LinkedEntity::Title(title) => LinkedEntity::Title(title),
+            LinkedEntity::Media(media) => LinkedEntity::Media(media),
+        };
+        PresentationChapter {
+            slides: self.slides,
+            linked_entity,
+            schema_version: self.schema_version,
+        }
+    }
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-pub enum SlideContent {
-    SingleLanguageMainContent(SingleLanguageMainContentSlide),
-    Title(TitleSlide),
-    MultiLanguageMainContent(MultiLanguageMainContentSlide),
-    SimplePicture(SimplePictureSlide),
-    Empty(EmptySlide),
-}
+    /// Counts the slides of the chapter by their content kind.
+    /// Only kinds which occur in the chapter are contained in the map.
+    pub fn count_by_kind(&self) -> HashMap<SlideContentKind, usize> {
+        let mut counts = HashMap::new();
+        for slide in &self.slides {
+            *counts.entry(slide.kind()).or_insert(0) += 1;
+        }
+        counts
+    }
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-pub struct SingleLanguageMainContentSlide {
-    pub main_text: String,
-    pub spoiler_text: Option<String>,
-    pub meta_text: Option<String>,
-}
+    /// Returns the indices of all slides whose text contains `query`, ignoring case.
+    /// An empty query matches no slides.
+    pub fn find_slides_containing(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let query = query.to_lowercase();
+        self.slides
+            .iter()
+            .enumerate()
+            .filter(|(_, slide)| {
+                slide
+                    .slide_content
+                    .all_text()
+                    .iter()
+                    .any(|text| text.to_lowercase().contains(&query))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
 
-impl SingleLanguageMainContentSlide {
-    fn new(main_text: String, spoiler_text: Option<String>, meta_text: Option<String>) -> Self {
-        let filter = |s: Option<String>| s.filter(|v| !v.trim().is_empty());
+    /// Retains only the slides for which `f` returns true, keeping their order
+    pub fn retain_slides<F>(&mut self, f: F)
+    where
+        F: FnMut(&Slide<M>) -> bool,
+    {
+        self.slides.retain(f);
+    }
+
+    /// Applies `f` to every slide of the chapter in order
+    pub fn map_slides<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Slide<M>),
+    {
+        self.slides.iter_mut().for_each(f);
+    }
+
+    /// Creates a chapter from the slides for which `f` returns a (possibly transformed) slide,
+    /// keeping their order and the linked entity
+    pub fn filter_map_slides<F>(self, f: F) -> Self
+    where
+        F: FnMut(Slide<M>) -> Option<Slide<M>>,
+    {
         Self {
-            main_text,
-            spoiler_text: filter(spoiler_text),
-            meta_text: filter(meta_text),
+            slides: self.slides.into_iter().filter_map(f).collect(),
+            ..self
         }
     }
-}
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-pub struct MultiLanguageMainContentSlide {
-    pub main_text_list: Vec<String>,
-    pub spoiler_text_vector: Vec<String>,
-    pub meta_text: Option<String>,
-}
+    /// Splits the chapter into two chapters at the given slide index.
+    /// The first chapter contains the slides `0..index`, the second one the remaining slides.
+    /// Both chapters are linked to the same entity.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of slides (like [`Vec::split_off`]).
+    pub fn split_at(mut self, index: usize) -> (Self, Self) {
+        let second_slides = self.slides.split_off(index);
+        let second = PresentationChapter {
+            slides: second_slides,
+            linked_entity: self.linked_entity.clone(),
+            schema_version: self.schema_version,
+        };
+        (self, second)
+    }
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-pub struct EmptySlide {
-    pub black_background: bool,
-}
+    /// Sorts the slides by their content kind, so title slides come first and empty slides last.
+    /// The sort is stable, slides of the same kind keep their order.
+    pub fn sort_slides_by_kind(&mut self) {
+        self.slides.sort_by_key(|slide| slide.kind());
+    }
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-pub struct TitleSlide {
-    pub title_text: String,
-    pub meta_text: Option<String>,
-}
+    /// Sorts the slides by their duration in ascending order, slides without a duration last.
+    /// The sort is stable, slides with the same (or without a) duration keep their order.
+    pub fn sort_slides_by_duration(&mut self) {
+        self.slides
+            .sort_by_key(|slide| (slide.duration_millis.is_none(), slide.duration_millis));
+    }
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-pub struct SimplePictureSlide {
-    pub picture_path: String,
-}
+    /// Removes consecutive duplicates of a slide.
+    /// The UUID is ignored for the comparison, as duplicates created separately never share it.
+    pub fn dedup_consecutive_slides(&mut self) {
+        self.slides.dedup_by(|a, b| a.eq_ignoring_uuid(b));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Links the given media to every slide of the chapter
+    pub fn set_media_all(&mut self, media: M) {
+        for slide in &mut self.slides {
+            slide.linked_file = Some(media.clone());
+        }
+    }
 
-    // Mocking your actual domain objects
-    #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-    pub struct Song {
-        pub id: u32,
-        pub title: String,
+    /// Removes the linked media from every slide of the chapter
+    pub fn clear_media_all(&mut self) {
+        for slide in &mut self.slides {
+            slide.linked_file = None;
+        }
     }
 
-    #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
-    pub struct SongFile {
-        pub path: String,
+    /// Resolves the linked media of every slide to a file path.
+    /// Returns the index and path of every slide whose media could be resolved.
+    pub fn resolve_media<R: MediaResolver<M>>(&self, resolver: &R) -> Vec<(usize, PathBuf)> {
+        self.slides
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slide)| {
+                let media = slide.linked_file.as_ref()?;
+                resolver.resolve(media).map(|path| (index, path))
+            })
+            .collect()
     }
 
-    // Creating type aliases makes the code much cleaner in the rest of your app
-    type SongPresentation = PresentationChapter<Song, SongFile>;
+    /// Returns the index and path of every picture slide whose picture does not exist on disk
+    pub fn validate_picture_paths(&self) -> Vec<(usize, String)> {
+        self.slides
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slide)| match &slide.slide_content {
+                SlideContent::SimplePicture(s) if !Path::new(&s.picture_path).exists() => {
+                    Some((index, s.picture_path.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
 
-    #[test]
-    fn test_generic_presentation_creation() {
-        let song = Song {
-            id: 1,
-            title: "Amazing Grace".to_string(),
-        };
-        let file = SongFile {
-            path: "/assets/grace.mp3".to_string(),
-        };
+    /// Inserts repeats of the given chorus slides after every verse.
+    /// A verse is a content slide which is not one of the choruses and whose section label,
+    /// if present, denotes a verse. Verses which are already followed by the choruses get no
+    /// repeat. The repeats get new UUIDs and are marked with `is_repeat`.
+    /// Indices which are out of range are ignored.
+    pub fn expand_choruses(&mut self, chorus_indices: &[usize]) {
+        let chorus_indices: Vec<usize> = chorus_indices
+            .iter()
+            .copied()
+            .filter(|&index| index < self.slides.len())
+            .collect();
+        if chorus_indices.is_empty() {
+            return;
+        }
+        let choruses: Vec<Slide<M>> = chorus_indices
+            .iter()
+            .map(|&index| {
+                let mut chorus = self.slides[index].duplicate();
+                chorus.is_repeat = true;
+                chorus
+            })
+            .collect();
 
-        let slide = Slide::new_content_slide(
-            "Amazing grace, how sweet the sound".to_string(),
-            None,
-            Some("Verse 1".to_string()),
-        )
-        .with_media(file.clone());
+        let slides = std::mem::take(&mut self.slides);
+        for (index, slide) in slides.into_iter().enumerate() {
+            let is_verse = !chorus_indices.contains(&index) && slide.is_verse();
+            self.slides.push(slide);
+            let followed_by_choruses = chorus_indices
+                .iter()
+                .enumerate()
+                .all(|(offset, &chorus)| chorus == index + 1 + offset);
+            if is_verse && !followed_by_choruses {
+                self.slides
+                    .extend(choruses.iter().map(|chorus| chorus.duplicate()));
+            }
+        }
+    }
 
-        let presentation = SongPresentation::new(vec![slide], LinkedEntity::Source(song));
+    /// Assigns an identifier to every slide of the chapter which has none yet
+    #[cfg(feature = "uuid")]
+    pub fn ensure_all_ids(&mut self) {
+        for slide in &mut self.slides {
+            slide.ensure_id();
+        }
+    }
 
-        assert_eq!(presentation.slides.len(), 1);
-        assert!(presentation.slides[0].linked_file.is_some());
+    /// Returns a copy of the chapter in which every slide has a newly generated UUID and id,
+    /// so the copy can be used next to the original without conflicts
+    #[cfg(feature = "uuid")]
+    pub fn clone_with_new_ids(&self) -> Self {
+        let mut chapter = Self {
+            slides: self.slides.iter().map(Slide::duplicate).collect(),
+            ..self.clone()
+        };
+        chapter.ensure_all_ids();
+        chapter
+    }
 
-        // Verify we can access our custom SongFile data
-        if let Some(ref media) = presentation.slides[0].linked_file {
-            assert_eq!(media.path, "/assets/grace.mp3");
+    /// Returns the first slide with the given identifier
+    pub fn slide_by_id(&self, id: &str) -> Option<&Slide<M>> {
+        self.slides
+            .iter()
+            .find(|slide| slide.id.as_deref() == Some(id))
+    }
+
+    /// Returns the first slide with the given identifier for modification
+    pub fn slide_by_id_mut(&mut self, id: &str) -> Option<&mut Slide<M>> {
+        self.slides
+            .iter_mut()
+            .find(|slide| slide.id.as_deref() == Some(id))
+    }
+
+    /// Returns every identifier which is used by more than one slide, in the order of their
+    /// first occurrence
+    pub fn duplicate_ids(&self) -> Vec<String> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for id in self.slides.iter().filter_map(|slide| slide.id.as_deref()) {
+            *counts.entry(id).or_insert(0) += 1;
+        }
+        let mut duplicates: Vec<String> = Vec::new();
+        for id in self.slides.iter().filter_map(|slide| slide.id.as_deref()) {
+            if counts[id] > 1 && !duplicates.iter().any(|d| d == id) {
+                duplicates.push(id.to_string());
+            }
         }
+        duplicates
     }
 
-    #[test]
-    fn test_serialization_roundtrip() {
-        let song = Song {
-            id: 42,
-            title: "Test Song".to_string(),
-        };
-        let presentation = PresentationChapter::<Song, SongFile>::new(
-            vec![Slide::new_title_slide("Title".to_string(), None)],
-            LinkedEntity::Source(song),
-        );
+    /// Sets the spoiler of every content slide to the first line of the next content slide.
+    /// Other slides in between are skipped, the spoiler of the last content slide is kept as it is.
+    /// A single-language slide followed by a multi-language slide gets the first line of its
+    /// primary language. A multi-language slide gets the first line of the same language of the
+    /// next slide if that one is a multi-language slide as well, spoilers of languages which the
+    /// next slide does not contain (or is a single-language slide) are kept as they are.
+    /// Empty first lines never replace a spoiler.
+    pub fn generate_spoilers(&mut self) {
+        fn first_line(text: &str) -> String {
+            text.lines().next().unwrap_or_default().to_string()
+        }
 
-        // Serialize to JSON
-        let json = serde_json::to_string(&presentation).unwrap();
+        // The first line of the next content slide and the first lines of its languages
+        let mut next: Option<(String, Vec<(String, String)>)> = None;
+        for slide in self.slides.iter_mut().rev() {
+            let first_lines = match &mut slide.slide_content {
+                SlideContent::SingleLanguageMainContent(s) => {
+                    if let Some((line, _)) = &next
+                        && !line.trim().is_empty()
+                    {
+                        s.spoiler_text = Some(line.clone());
+                    }
+                    (first_line(&s.main_text), Vec::new())
+                }
+                SlideContent::MultiLanguageMainContent(s) => {
+                    let language_count = s.main_text_list.len();
+                    for (index, code) in s.language_codes.iter().enumerate().take(language_count) {
+                        let line = next.as_ref().and_then(|(_, lines)| {
+                            lines
+                                .iter()
+                                .find(|(c, line)| c == code && !line.trim().is_empty())
+                        });
+                        if let Some((_, line)) = line {
+                            if s.spoiler_text_vector.len() < language_count {
+                                s.spoiler_text_vector.resize(language_count, String::new());
+                            }
+                            s.spoiler_text_vector[index] = line.clone();
+                        }
+                    }
+                    (
+                        first_line(s.primary_main_text().unwrap_or_default()),
+                        s.language_codes
+                            .iter()
+                            .zip(&s.main_text_list)
+                            .map(|(code, text)| (code.clone(), first_line(text)))
+                            .collect(),
+                    )
+                }
+                _ => continue,
+            };
+            next = Some(first_lines);
+        }
+    }
 
-        // Deserialize back
-        let deserialized: PresentationChapter<Song, SongFile> =
-            serde_json::from_str(&json).unwrap();
+    /// Replaces every multi-language slide containing the given language by a single-language
+    /// slide with that language. Slides without the language are kept as they are.
+    pub fn collapse_to_language(&mut self, code: &str) {
+        for slide in &mut self.slides {
+            if let SlideContent::MultiLanguageMainContent(s) = &slide.slide_content
+                && let Some(single) = s.to_single_language(code)
+            {
+                slide.slide_content = SlideContent::SingleLanguageMainContent(single);
+            }
+        }
+    }
 
-        assert_eq!(presentation, deserialized);
-        if let LinkedEntity::Source(s) = &deserialized.linked_entity {
-            assert_eq!(s.id, 42);
+    /// Removes the spoilers from all content slides, e.g. for printed handouts
+    pub fn clear_spoilers(&mut self) {
+        for slide in &mut self.slides {
+            match &mut slide.slide_content {
+                SlideContent::SingleLanguageMainContent(s) => s.spoiler_text = None,
+                SlideContent::MultiLanguageMainContent(s) => s.spoiler_text_vector.clear(),
+                _ => {}
+            }
         }
     }
 
-    #[test]
-    fn test_different_generic_types() {
-        // This proves the library isn't tied to 'Song' anymore.
-        // We can use simple Strings for everything.
-        let presentation = PresentationChapter::<String, String>::new(
-            vec![Slide::new_empty_slide(true).with_media("background.png".into())],
-            LinkedEntity::Title("Simple Show".into()),
-        );
+    /// Returns the number of slides in the chapter
+    pub fn len(&self) -> usize {
+        self.slides.len()
+    }
 
-        assert_eq!(
-            presentation.linked_entity,
+    /// Returns true if the chapter contains no slides
+    pub fn is_empty(&self) -> bool {
+        self.slides.is_empty()
+    }
+
+    /// Returns the slide at the given index, or `None` if the index is out of bounds
+    pub fn get(&self, index: usize) -> Option<&Slide<M>> {
+        self.slides.get(index)
+    }
+
+    /// Inserts a slide at the given index, shifting all following slides.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of slides (like [`Vec::insert`]).
+    pub fn insert_slide(&mut self, index: usize, slide: Slide<M>) {
+        self.slides.insert(index, slide);
+    }
+
+    /// Removes and returns the slide at the given index, shifting all following slides.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds (like [`Vec::remove`]).
+    pub fn remove_slide(&mut self, index: usize) -> Slide<M> {
+        self.slides.remove(index)
+    }
+
+    /// Removes and returns the slide at the given index, or `None` if the index is out of bounds
+    pub fn try_remove_slide(&mut self, index: usize) -> Option<Slide<M>> {
+        (index < self.slides.len()).then(|| self.slides.remove(index))
+    }
+
+    /// Reverses the order of the slides.
+    /// Spoilers refer to the following slide, so they should be regenerated afterwards
+    /// (see [`PresentationChapter::generate_spoilers`]).
+    pub fn reverse_slides(&mut self) {
+        self.slides.reverse();
+    }
+
+    /// Returns the chapter with the order of the slides reversed.
+    /// Spoilers should be regenerated afterwards, see [`PresentationChapter::reverse_slides`].
+    pub fn reversed(mut self) -> Self {
+        self.reverse_slides();
+        self
+    }
+
+    /// Compares two chapters while ignoring the attached media, e.g. to compare an imported chapter
+    /// against its canonical source. Of the linked entities only the kind is compared, e.g. two
+    /// chapters linked to different titles are considered equal.
+    pub fn content_eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(&self.linked_entity) == std::mem::discriminant(&other.linked_entity)
+            && self.slides.len() == other.slides.len()
+            && self
+                .slides
+                .iter()
+                .zip(&other.slides)
+                .all(|(a, b)| a.content_eq(b))
+    }
+
+    /// Returns a fingerprint of the content of all slides, e.g. to detect whether a cached render
+    /// is stale. UUIDs, ids and linked files are not part of the fingerprint. It is computed
+    /// with a fixed hash function (FNV-1a) over the content itself rather than its serialized form,
+    /// so it is reproducible across runs, platforms and cargo features.
+    pub fn content_fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        for slide in &self.slides {
+            (
+                &slide.slide_content,
+                &slide.transition,
+                slide.duration_millis,
+                &slide.notes,
+                &slide.background,
+                slide.is_repeat,
+            )
+                .hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Cleans up the whitespace of all text in the chapter, e.g. after importing lyrics.
+    /// This covers the visible text as well as notes, section labels, alternative texts of
+    /// pictures and the title of a linked title entity.
+    /// Text is trimmed, runs of spaces and tabs are collapsed to a single space, whitespace
+    /// at the end of lines is removed and more than one blank line is collapsed to one.
+    pub fn normalize_text(&mut self) {
+        for text in self.all_text_fields_mut() {
+            *text = normalize_whitespace(text);
+        }
+    }
+
+    /// Returns mutable references to all text of the chapter, including the text which is not
+    /// shown on the slides (notes, section labels, alternative texts and the chapter title)
+    pub(crate) fn all_text_fields_mut(&mut self) -> Vec<&mut String> {
+        let mut fields: Vec<&mut String> = Vec::new();
+        if let LinkedEntity::Title(title) = &mut self.linked_entity {
+            fields.push(title);
+        }
+        for slide in &mut self.slides {
+            fields.extend(slide.notes.as_mut());
+            fields.extend(slide.slide_content.all_text_fields_mut());
+        }
+        fields
+    }
+
+    /// Returns the number of words of all visible text in the chapter
+    pub fn word_count(&self) -> usize {
+        self.slides
+            .iter()
+            .map(|slide| slide.slide_content.word_count())
+            .sum()
+    }
+
+    /// Returns the number of displayed lines of all visible text in the chapter
+    pub fn total_line_count(&self) -> usize {
+        self.slides
+            .iter()
+            .map(|slide| slide.slide_content.line_count())
+            .sum()
+    }
+
+    /// Estimates the time needed to read all text of the chapter at the given reading speed.
+    /// A speed of zero words per minute returns [`Duration::ZERO`].
+    pub fn estimated_reading_time(&self, words_per_minute: u32) -> Duration {
+        if words_per_minute == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_millis(self.word_count() as u64 * 60_000 / u64::from(words_per_minute))
+    }
+
+    /// Inserts a title slide at the front unless the chapter already starts with one.
+    /// The title is taken from a non-empty `LinkedEntity::Title`, otherwise `fallback_title` is used.
+    pub fn ensure_title_first(&mut self, fallback_title: &str) {
+        if self.slides.first().is_some_and(|slide| slide.is_title()) {
+            return;
+        }
+        let title = match &self.linked_entity {
+            LinkedEntity::Title(title) if !title.trim().is_empty() => title.as_str(),
+            _ => fallback_title,
+        };
+        self.slides
+            .insert(0, Slide::new_title_slide(title.to_string(), None));
+    }
+
+    /// Combines consecutive single-language content slides as long as the combined main text
+    /// has at most `max_lines` lines. The main texts are joined with line breaks, the spoiler and
+    /// meta text are taken from the last slide. Only slides with the same presentation metadata,
+    /// linked file, alignment, direction and section label are combined.
+    pub fn combine_short_slides(&mut self, max_lines: usize) {
+        let slides = std::mem::take(&mut self.slides);
+        for slide in slides {
+            if let Some(previous) = self.slides.last_mut()
+                && let SlideContent::SingleLanguageMainContent(first) = &mut previous.slide_content
+                && let SlideContent::SingleLanguageMainContent(second) = &slide.slide_content
+                && first.main_text.lines().count() + second.main_text.lines().count() <= max_lines
+                && first.alignment == second.alignment
+                && first.direction == second.direction
+                && first.section_label == second.section_label
+                && previous.transition == slide.transition
+                && previous.duration_millis == slide.duration_millis
+                && previous.notes == slide.notes
+                && previous.background == slide.background
+                && previous.is_repeat == slide.is_repeat
+                && previous.linked_file == slide.linked_file
+            {
+                first.append(second);
+                continue;
+            }
+            self.slides.push(slide);
+        }
+    }
+
+    /// Summarizes the content of the chapter
+    pub fn stats(&self) -> ChapterStats {
+        let mut languages: Vec<String> = Vec::new();
+        for slide in &self.slides {
+            if let SlideContent::MultiLanguageMainContent(s) = &slide.slide_content {
+                for code in &s.language_codes {
+                    if !languages.contains(code) {
+                        languages.push(code.clone());
+                    }
+                }
+            }
+        }
+        ChapterStats {
+            slide_count: self.slides.len(),
+            content_slide_count: self
+                .slides
+                .iter()
+                .filter(|slide| {
+                    matches!(
+                        slide.kind(),
+                        SlideContentKind::SingleLanguageMainContent
+                            | SlideContentKind::MultiLanguageMainContent
+                    )
+                })
+                .count(),
+            word_count: self.word_count(),
+            has_title: self.slides.iter().any(|slide| slide.is_title()),
+            has_pictures: self
+                .slides
+                .iter()
+                .any(|slide| slide.kind() == SlideContentKind::SimplePicture),
+            languages,
+        }
+    }
+
+    /// Separates the slides into those for which `f` returns true and all others,
+    /// keeping the order within both groups
+    pub fn partition_slides<F>(self, f: F) -> (Vec<Slide<M>>, Vec<Slide<M>>)
+    where
+        F: FnMut(&Slide<M>) -> bool,
+    {
+        self.slides.into_iter().partition(f)
+    }
+
+    /// Returns the slides in consecutive, non-overlapping slices of `size` slides,
+    /// the last slice may be shorter.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero (like [`slice::chunks`]).
+    pub fn chunk_slides(&self, size: usize) -> Vec<&[Slide<M>]> {
+        self.slides.chunks(size).collect()
+    }
+
+    /// Returns an iterator over the slides of the chapter
+    pub fn iter(&self) -> std::slice::Iter<'_, Slide<M>> {
+        self.slides.iter()
+    }
+}
+
+// Needs no bounds, so that the generic exporters can use it as well
+impl<T, M> PresentationChapter<T, M> {
+    /// Returns the human-readable title of the chapter: the text of the first title slide,
+    /// or else the title of the linked entity if it is not empty
+    pub fn display_title(&self) -> Option<String> {
+        self.slides
+            .iter()
+            .find_map(|slide| match &slide.slide_content {
+                SlideContent::Title(s) => Some(s.title_text.clone()),
+                _ => None,
+            })
+            .or_else(|| match &self.linked_entity {
+                LinkedEntity::Title(title) if !title.is_empty() => Some(title.clone()),
+                _ => None,
+            })
+    }
+}
+
+impl<'a, T, M> IntoIterator for &'a PresentationChapter<T, M> {
+    type Item = &'a Slide<M>;
+    type IntoIter = std::slice::Iter<'a, Slide<M>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slides.iter()
+    }
+}
+
+/// Appends the slides to the chapter, the linked entity of the chapter is left unchanged
+impl<T, M> Extend<Slide<M>> for PresentationChapter<T, M> {
+    fn extend<I: IntoIterator<Item = Slide<M>>>(&mut self, iter: I) {
+        self.slides.extend(iter);
+    }
+}
+
+impl<T, M> std::ops::Index<usize> for PresentationChapter<T, M> {
+    type Output = Slide<M>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.slides[index]
+    }
+}
+
+impl<T, M> std::ops::IndexMut<usize> for PresentationChapter<T, M> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.slides[index]
+    }
+}
+
+impl<T, M> IntoIterator for PresentationChapter<T, M> {
+    type Item = Slide<M>;
+    type IntoIter = std::vec::IntoIter<Slide<M>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slides.into_iter()
+    }
+}
+
+impl<M> Slide<M>
+where
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    fn from_content(slide_content: SlideContent) -> Self {
+        Self {
+            uuid: Uuid::now_v7(),
+            id: None,
+            slide_content,
+            linked_file: None,
+            transition: None,
+            duration_millis: None,
+            notes: None,
+            background: None,
+            is_repeat: false,
+        }
+    }
+
+    pub fn new_empty_slide(black_background: bool) -> Self {
+        Self::from_content(SlideContent::Empty(EmptySlide { black_background }))
+    }
+
+    pub fn new_content_slide(
+        main_text: String,
+        spoiler_text: Option<String>,
+        meta_text: Option<String>,
+    ) -> Self {
+        Self::from_content(SlideContent::SingleLanguageMainContent(
+            SingleLanguageMainContentSlide::new(
+                main_text.trim().to_string(),
+                spoiler_text.map(|s| s.trim().to_string()),
+                meta_text.map(|s| s.trim().to_string()),
+            ),
+        ))
+    }
+
+    pub fn new_multi_language_content_slide(
+        main_texts: Vec<String>,
+        spoiler_texts: Vec<String>,
+        meta_text: Option<String>,
+    ) -> Self {
+        Self::from_content(SlideContent::MultiLanguageMainContent(
+            MultiLanguageMainContentSlide::new(
+                main_texts.iter().map(|s| s.trim().to_string()).collect(),
+                spoiler_texts.iter().map(|s| s.trim().to_string()).collect(),
+                meta_text.map(|s| s.trim().to_string()),
+            ),
+        ))
+    }
+
+    pub fn new_title_slide(title_text: String, meta_text: Option<String>) -> Self {
+        Self::from_content(SlideContent::Title(TitleSlide {
+            title_text: title_text.trim().to_string(),
+            meta_text: meta_text.map(|s| s.trim().to_string()),
+        }))
+    }
+
+    pub fn new_video_slide(video_path: String, loop_playback: bool, autoplay: bool) -> Self {
+        Self::from_content(SlideContent::Video(VideoSlide {
+            video_path: video_path.trim().to_string(),
+            loop_playback,
+            autoplay,
+        }))
+    }
+
+    /// Creates a countdown over `target_seconds` seconds on a black background
+    pub fn new_countdown_slide(target_seconds: u32, message: Option<String>) -> Self {
+        Self::from_content(SlideContent::Countdown(CountdownSlide {
+            target_seconds,
+            message: message
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            black_background: true,
+        }))
+    }
+
+    pub fn with_media(mut self, media: M) -> Self {
+        self.linked_file = Some(media);
+        self
+    }
+
+    pub fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    pub fn with_duration(mut self, duration_millis: u32) -> Self {
+        self.duration_millis = Some(duration_millis);
+        self
+    }
+
+    pub fn with_notes(mut self, notes: String) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    pub fn with_background(mut self, background: Background) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self.slide_content, SlideContent::Empty(_))
+    }
+
+    /// Converts a title slide into a content slide, see [`SlideContent::title_to_content`]
+    pub fn title_to_content(&mut self) {
+        self.slide_content = std::mem::take(&mut self.slide_content).title_to_content();
+    }
+
+    /// Converts a content slide into a title slide, see [`SlideContent::content_to_title`]
+    pub fn content_to_title(&mut self) {
+        self.slide_content = std::mem::take(&mut self.slide_content).content_to_title();
+    }
+
+    pub fn is_title(&self) -> bool {
+        matches!(self.slide_content, SlideContent::Title(_))
+    }
+
+    /// Returns true for single- and multi-language content slides
+    pub fn is_content(&self) -> bool {
+        matches!(
+            self.slide_content,
+            SlideContent::SingleLanguageMainContent(_) | SlideContent::MultiLanguageMainContent(_)
+        )
+    }
+
+    pub fn is_picture(&self) -> bool {
+        matches!(self.slide_content, SlideContent::SimplePicture(_))
+    }
+
+    pub fn has_spoiler(&self) -> bool {
+        match &self.slide_content {
+            SlideContent::SingleLanguageMainContent(s) => s.spoiler_text.is_some(),
+            SlideContent::MultiLanguageMainContent(s) => {
+                s.spoiler_text_vector.iter().any(|t| !t.is_empty())
+            }
+            _ => false,
+        }
+    }
+
+    pub fn has_meta_text(&self) -> bool {
+        self.slide_content.meta_text().is_some()
+    }
+
+    /// Checks whether the slide would render the expected content
+    pub fn validate(&self) -> Result<(), SlideValidationError> {
+        match &self.slide_content {
+            SlideContent::SingleLanguageMainContent(s) if s.main_text.trim().is_empty() => {
+                Err(SlideValidationError::EmptyMainText)
+            }
+            SlideContent::MultiLanguageMainContent(s)
+                if s.main_text_list.iter().all(|t| t.trim().is_empty()) =>
+            {
+                Err(SlideValidationError::EmptyMainText)
+            }
+            SlideContent::Title(s) if s.title_text.trim().is_empty() => {
+                Err(SlideValidationError::EmptyTitle)
+            }
+            SlideContent::SimplePicture(s) if s.picture_path.trim().is_empty() => {
+                Err(SlideValidationError::EmptyPicturePath)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Transforms the media type of the slide by applying `f` to the linked file
+    pub fn map_media<N, F>(self, f: F) -> Slide<N>
+    where
+        F: FnOnce(M) -> N,
+    {
+        Slide {
+            uuid: self.uuid,
+            id: self.id,
+            slide_content: self.slide_content,
+            linked_file: self.linked_file.map(f),
+            transition: self.transition,
+            duration_millis: self.duration_millis,
+            notes: self.notes,
+            background: self.background,
+            is_repeat: self.is_repeat,
+        }
+    }
+
+    /// Compares two slides while ignoring their UUIDs, ids and linked files
+    pub fn content_eq(&self, other: &Self) -> bool {
+        let Self {
+            uuid: _,
+            id: _,
+            slide_content,
+            linked_file: _,
+            transition,
+            duration_millis,
+            notes,
+            background,
+            is_repeat,
+        } = self;
+        *slide_content == other.slide_content
+            && *transition == other.transition
+            && *duration_millis == other.duration_millis
+            && *notes == other.notes
+            && *background == other.background
+            && *is_repeat == other.is_repeat
+    }
+
+    /// Compares all fields of two slides except their UUIDs and ids
+    fn eq_ignoring_uuid(&self, other: &Self) -> bool {
+        self.content_eq(other) && self.linked_file == other.linked_file
+    }
+
+    /// Returns a key which compares and hashes the slide by all fields except its UUID and id,
+    /// e.g. to deduplicate imported slides in a `HashSet`
+    pub fn key(&self) -> SlideKey<'_, M> {
+        SlideKey(self)
+    }
+
+    /// Returns a copy of the slide with a newly generated UUID and without an id,
+    /// so that the copy is never mistaken for the original
+    pub(crate) fn duplicate(&self) -> Self {
+        Self {
+            uuid: Uuid::now_v7(),
+            id: None,
+            ..self.clone()
+        }
+    }
+
+    /// Checks whether the slide is a content slide without a section label or with a verse label
+    fn is_verse(&self) -> bool {
+        let section_kind = match &self.slide_content {
+            SlideContent::SingleLanguageMainContent(s) => s.section_kind(),
+            SlideContent::MultiLanguageMainContent(s) => s.section_kind(),
+            _ => return false,
+        };
+        matches!(section_kind, None | Some(SectionKind::Verse(_)))
+    }
+
+    /// Assigns a random identifier (UUID v4) to the slide if it has none yet
+    #[cfg(feature = "uuid")]
+    pub fn ensure_id(&mut self) {
+        if self.id.is_none() {
+            self.id = Some(Uuid::new_v4().to_string());
+        }
+    }
+
+    pub fn has_notes(&self) -> bool {
+        self.notes.is_some()
+    }
+
+    /// Returns the kind of the slide content
+    pub fn kind(&self) -> SlideContentKind {
+        self.slide_content.kind()
+    }
+
+    /// Returns the UUID of the slide (UUID v7)
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+}
+
+/// A slide compared and hashed by all fields except its UUID and id, see [`Slide::key`]
+#[derive(Clone, Copy, Debug)]
+pub struct SlideKey<'a, M>(pub &'a Slide<M>);
+
+impl<M> PartialEq for SlideKey<'_, M>
+where
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignoring_uuid(other.0)
+    }
+}
+
+impl<M> Eq for SlideKey<'_, M> where
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug
+{
+}
+
+impl<M: Hash> Hash for SlideKey<'_, M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Slide {
+            uuid: _,
+            id: _,
+            slide_content,
+            linked_file,
+            transition,
+            duration_millis,
+            notes,
+            background,
+            is_repeat,
+        } = self.0;
+        slide_content.hash(state);
+        linked_file.hash(state);
+        transition.hash(state);
+        duration_millis.hash(state);
+        notes.hash(state);
+        background.hash(state);
+        is_repeat.hash(state);
+    }
+}
+
+/// Reorders the leading values so that the value at `permutation[i]` ends up at index `i`
+fn reorder<V: Clone>(values: &mut [V], permutation: &[usize]) {
+    let reordered: Vec<V> = permutation.iter().map(|&i| values[i].clone()).collect();
+    values[..reordered.len()].clone_from_slice(&reordered);
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    let mut normalized = text
+        .trim()
+        .split('\n')
+        .map(|line| {
+            line.split([' ', '\t', '\r'])
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    while normalized.contains("\n\n\n") {
+        normalized = normalized.replace("\n\n\n", "\n\n");
+    }
+    normalized
+}
+
+/// Converts text to Markdown paragraphs, ending every line with a hard line break (`\`).
+/// Blank lines separate paragraphs.
+fn markdown_text(text: &str) -> String {
+    let mut paragraphs: Vec<Vec<String>> = vec![Vec::new()];
+    for line in text.lines().map(str::trim_end) {
+        let paragraph = paragraphs.last_mut().expect("there is always a paragraph");
+        if !line.is_empty() {
+            paragraph.push(escape_markdown(line));
+        } else if !paragraph.is_empty() {
+            paragraphs.push(Vec::new());
+        }
+    }
+    paragraphs
+        .iter()
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| paragraph.join("\\\n"))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Returns the link destination for a path, enclosed in angle brackets (`<...>`) if it contains
+/// whitespace, parentheses or other characters which would end a plain destination
+fn markdown_destination(path: &str) -> String {
+    if !path
+        .chars()
+        .any(|c| c.is_whitespace() || matches!(c, '(' | ')' | '<' | '>' | '\\'))
+    {
+        return path.to_string();
+    }
+    let mut destination = String::with_capacity(path.len() + 2);
+    destination.push('<');
+    for c in path.chars() {
+        if matches!(c, '<' | '>' | '\\') {
+            destination.push('\\');
+        }
+        destination.push(c);
+    }
+    destination.push('>');
+    destination
+}
+
+/// Escapes the characters of a single line which Markdown would interpret as formatting
+fn escape_markdown(line: &str) -> String {
+    let mut escaped = String::with_capacity(line.len());
+    for (index, c) in line.chars().enumerate() {
+        let starts_block = index == 0 && matches!(c, '-' | '+' | '=' | '|');
+        if starts_block
+            || matches!(
+                c,
+                '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '<' | '>' | '~'
+            )
+        {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The 64-bit FNV-1a hash function, which unlike the standard hasher is stable across runs.
+/// Integers are hashed as little-endian 64-bit values, so the hash does not depend on the platform.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write_u64(value.into());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write_u64(value.into());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+}
+
+/// Groups consecutive slides with an equal key, e.g. to regroup a flattened slide stream into chapters.
+/// Slides with the same key which are not adjacent end up in separate groups.
+pub fn group_consecutive<M, K, F>(slides: &[Slide<M>], key: F) -> Vec<(K, Vec<&Slide<M>>)>
+where
+    F: Fn(&Slide<M>) -> K,
+    K: PartialEq,
+{
+    let mut groups: Vec<(K, Vec<&Slide<M>>)> = Vec::new();
+    for slide in slides {
+        let slide_key = key(slide);
+        match groups.last_mut() {
+            Some((group_key, group)) if *group_key == slide_key => group.push(slide),
+            _ => groups.push((slide_key, vec![slide])),
+        }
+    }
+    groups
+}
+
+impl<M> std::fmt::Display for Slide<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.slide_content)
+    }
+}
+
+// --- Content Definitions ---
+
+/// The content of a slide.
+/// By default it is serialized externally tagged (`{"Title": {...}}`). With the `tagged-content`
+/// feature it is serialized internally tagged (`{"type": "Title", ...}`) instead, while the
+/// externally tagged form can still be read.
+#[derive(Clone, Serialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(not(feature = "tagged-content"), derive(Deserialize))]
+#[cfg_attr(feature = "tagged-content", serde(tag = "type"))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub enum SlideContent {
+    SingleLanguageMainContent(SingleLanguageMainContentSlide),
+    Title(TitleSlide),
+    MultiLanguageMainContent(MultiLanguageMainContentSlide),
+    SimplePicture(SimplePictureSlide),
+    Empty(EmptySlide),
+    Video(VideoSlide),
+    Countdown(CountdownSlide),
+}
+
+/// The kind of slide content, without the content itself.
+/// Kinds are ordered by the declaration order: title slides come first, empty slides last.
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SlideContentKind {
+    Title,
+    SingleLanguageMainContent,
+    MultiLanguageMainContent,
+    SimplePicture,
+    Video,
+    Countdown,
+    Empty,
+}
+
+impl SlideContent {
+    /// Returns the kind of the content
+    pub fn kind(&self) -> SlideContentKind {
+        match self {
+            SlideContent::SingleLanguageMainContent(_) => {
+                SlideContentKind::SingleLanguageMainContent
+            }
+            SlideContent::Title(_) => SlideContentKind::Title,
+            SlideContent::MultiLanguageMainContent(_) => SlideContentKind::MultiLanguageMainContent,
+            SlideContent::SimplePicture(_) => SlideContentKind::SimplePicture,
+            SlideContent::Empty(_) => SlideContentKind::Empty,
+            SlideContent::Video(_) => SlideContentKind::Video,
+            SlideContent::Countdown(_) => SlideContentKind::Countdown,
+        }
+    }
+
+    /// Returns all visible text of the content, e.g. for search indexing.
+    /// Picture, video and empty slides contain no text.
+    pub fn all_text(&self) -> Vec<&str> {
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => std::iter::once(s.main_text.as_str())
+                .chain(s.spoiler_text.as_deref())
+                .chain(s.meta_text.as_deref())
+                .collect(),
+            SlideContent::Title(s) => std::iter::once(s.title_text.as_str())
+                .chain(s.meta_text.as_deref())
+                .collect(),
+            SlideContent::MultiLanguageMainContent(s) => s
+                .main_text_list
+                .iter()
+                .chain(s.spoiler_text_vector.iter().filter(|t| !t.is_empty()))
+                .map(String::as_str)
+                .chain(s.meta_text.as_deref())
+                .chain(
+                    s.meta_text_list
+                        .iter()
+                        .filter(|t| !t.is_empty())
+                        .map(String::as_str),
+                )
+                .collect(),
+            SlideContent::Countdown(s) => s.message.as_deref().into_iter().collect(),
+            SlideContent::SimplePicture(_) | SlideContent::Empty(_) | SlideContent::Video(_) => {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns the number of characters of all visible text of the content
+    pub fn char_count(&self) -> usize {
+        self.all_text()
+            .iter()
+            .map(|text| text.chars().count())
+            .sum()
+    }
+
+    /// Returns the number of whitespace-separated words of all visible text of the content
+    pub fn word_count(&self) -> usize {
+        self.all_text()
+            .iter()
+            .map(|text| text.split_whitespace().count())
+            .sum()
+    }
+
+    /// Returns the number of displayed lines of all visible text of the content.
+    /// Multi-language slides count the lines of every language.
+    pub fn line_count(&self) -> usize {
+        self.all_text()
+            .iter()
+            .map(|text| text.lines().count())
+            .sum()
+    }
+
+    /// Returns mutable references to all visible text of the content, see [`SlideContent::all_text`]
+    pub(crate) fn text_fields_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => std::iter::once(&mut s.main_text)
+                .chain(s.spoiler_text.as_mut())
+                .chain(s.meta_text.as_mut())
+                .collect(),
+            SlideContent::Title(s) => std::iter::once(&mut s.title_text)
+                .chain(s.meta_text.as_mut())
+                .collect(),
+            SlideContent::MultiLanguageMainContent(s) => s
+                .main_text_list
+                .iter_mut()
+                .chain(s.spoiler_text_vector.iter_mut())
+                .chain(s.meta_text.as_mut())
+                .chain(s.meta_text_list.iter_mut())
+                .collect(),
+            SlideContent::Countdown(s) => s.message.as_mut().into_iter().collect(),
+            SlideContent::SimplePicture(_) | SlideContent::Empty(_) | SlideContent::Video(_) => {
+                Vec::new()
+            }
+        }
+    }
+
+    /// Returns mutable references to all visible text of the content (see
+    /// [`SlideContent::text_fields_mut`]) together with the section label and alternative text
+    pub(crate) fn all_text_fields_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => std::iter::once(&mut s.main_text)
+                .chain(s.spoiler_text.as_mut())
+                .chain(s.meta_text.as_mut())
+                .chain(s.section_label.as_mut())
+                .collect(),
+            SlideContent::MultiLanguageMainContent(s) => s
+                .main_text_list
+                .iter_mut()
+                .chain(s.spoiler_text_vector.iter_mut())
+                .chain(s.meta_text.as_mut())
+                .chain(s.meta_text_list.iter_mut())
+                .chain(s.section_label.as_mut())
+                .collect(),
+            SlideContent::SimplePicture(s) => s.alt_text.as_mut().into_iter().collect(),
+            content => content.text_fields_mut(),
+        }
+    }
+
+    /// Returns the meta text of the content, if the content supports one and it is set
+    pub fn meta_text(&self) -> Option<&str> {
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => s.meta_text.as_deref(),
+            SlideContent::Title(s) => s.meta_text.as_deref(),
+            SlideContent::MultiLanguageMainContent(s) => s.meta_text.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Converts a title into a single-language content slide with the title text as main text.
+    /// The meta text is kept, any other content is returned unchanged.
+    pub fn title_to_content(self) -> SlideContent {
+        match self {
+            SlideContent::Title(s) => {
+                SlideContent::SingleLanguageMainContent(SingleLanguageMainContentSlide {
+                    main_text: s.title_text,
+                    meta_text: s.meta_text,
+                    ..Default::default()
+                })
+            }
+            content => content,
+        }
+    }
+
+    /// Converts a single-language content slide into a title with the main text as title text.
+    /// The meta text is kept, the spoiler text and the other settings of the content slide are
+    /// dropped since a title cannot hold them. Any other content is returned unchanged.
+    pub fn content_to_title(self) -> SlideContent {
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => SlideContent::Title(TitleSlide {
+                title_text: s.main_text,
+                meta_text: s.meta_text,
+            }),
+            content => content,
+        }
+    }
+}
+
+/// The default content is an empty slide with a black background
+impl Default for SlideContent {
+    fn default() -> Self {
+        SlideContent::Empty(EmptySlide::default())
+    }
+}
+
+impl std::fmt::Display for SlideContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => write!(f, "{}", s.main_text),
+            SlideContent::Title(s) => write!(f, "{}", s.title_text),
+            SlideContent::MultiLanguageMainContent(s) => {
+                write!(f, "{}", s.main_text_list.join(" / "))
+            }
+            SlideContent::SimplePicture(s) => write!(f, "[picture: {}]", s.picture_path),
+            SlideContent::Empty(_) => write!(f, "[empty]"),
+            SlideContent::Video(s) => write!(f, "[video: {}]", s.video_path),
+            SlideContent::Countdown(s) => write!(f, "[countdown: {}s]", s.target_seconds),
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct SingleLanguageMainContentSlide {
+    pub main_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spoiler_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta_text: Option<String>,
+    /// The alignment of the main text, renderers use their own default if it is not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alignment: Option<TextAlignment>,
+    /// The writing direction of the text, left-to-right is assumed if it is not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub direction: Option<TextDirection>,
+    /// The label of the song section (e.g. "Verse 1" or "Chorus") shown to the operator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section_label: Option<String>,
+    /// The chords to show above the main text for musicians
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chords: Option<Vec<ChordAnnotation>>,
+}
+
+impl SingleLanguageMainContentSlide {
+    fn new(main_text: String, spoiler_text: Option<String>, meta_text: Option<String>) -> Self {
+        let filter = |s: Option<String>| s.filter(|v| !v.trim().is_empty());
+        Self {
+            main_text,
+            spoiler_text: filter(spoiler_text),
+            meta_text: filter(meta_text),
+            alignment: None,
+            direction: None,
+            section_label: None,
+            chords: None,
+        }
+    }
+
+    pub fn with_section(mut self, section: SectionKind) -> Self {
+        self.section_label = Some(section.to_string());
+        self
+    }
+
+    /// Returns the kind of song section given by the section label
+    pub fn section_kind(&self) -> Option<SectionKind> {
+        self.section_label.as_deref().map(SectionKind::parse)
+    }
+
+    /// Checks whether the text is written right-to-left
+    pub fn is_rtl(&self) -> bool {
+        self.direction == Some(TextDirection::Rtl)
+    }
+
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Appends the main text of another slide on a new line and takes over its spoiler and
+    /// meta text. The chord annotations of the other slide are moved along with its text.
+    fn append(&mut self, other: &SingleLanguageMainContentSlide) {
+        let offset = self.main_text.chars().count() + 1;
+        if let Some(chords) = &other.chords {
+            self.chords
+                .get_or_insert_with(Vec::new)
+                .extend(chords.iter().map(|chord| ChordAnnotation {
+                    char_offset: chord.char_offset + offset,
+                    chord: chord.chord.clone(),
+                }));
+        }
+        self.main_text.push('\n');
+        self.main_text.push_str(&other.main_text);
+        self.spoiler_text = other.spoiler_text.clone();
+        self.meta_text = other.meta_text.clone();
+    }
+
+    /// Returns the chord annotations on the given (0-based) line of the main text.
+    /// An annotation at the position of a line break belongs to the line it ends.
+    pub fn chords_at_line(&self, line: usize) -> Vec<&ChordAnnotation> {
+        let line_starts = self.line_starts();
+        let Some(&start) = line_starts.get(line) else {
+            return Vec::new();
+        };
+        let end = line_starts.get(line + 1).copied().unwrap_or(usize::MAX);
+        self.chords
+            .iter()
+            .flatten()
+            .filter(|chord| chord.char_offset >= start && chord.char_offset < end)
+            .collect()
+    }
+
+    /// Returns the character offset at which each line of the main text starts
+    fn line_starts(&self) -> Vec<usize> {
+        let mut start = 0;
+        self.main_text
+            .split('\n')
+            .map(|line| {
+                let line_start = start;
+                start += line.chars().count() + 1;
+                line_start
+            })
+            .collect()
+    }
+
+    /// Converts the slide into a multi-language slide with the given language as the only language
+    pub fn into_multi_language(self, language_code: String) -> MultiLanguageMainContentSlide {
+        MultiLanguageMainContentSlide {
+            main_text_list: vec![self.main_text],
+            spoiler_text_vector: self.spoiler_text.into_iter().collect(),
+            meta_text: self.meta_text,
+            language_codes: vec![language_code],
+            alignment: self.alignment,
+            directions: self.direction.into_iter().collect(),
+            section_label: self.section_label,
+            meta_text_list: Vec::new(),
+            primary_language_index: None,
+        }
+    }
+
+    /// Splits the main text on line boundaries into slides with at most `max_lines` lines each.
+    /// The meta text is carried onto every produced slide, the spoiler text only onto the last one.
+    /// Chord annotations move to the slide containing their line.
+    /// A slide which already fits (or a `max_lines` of zero) returns a single-element vector.
+    pub fn split_by_lines(&self, max_lines: usize) -> Vec<SingleLanguageMainContentSlide> {
+        let lines: Vec<&str> = self.main_text.lines().collect();
+        if max_lines == 0 || lines.len() <= max_lines {
+            return vec![self.clone()];
+        }
+
+        let group_starts: Vec<usize> = (0..lines.len()).step_by(max_lines).collect();
+        self.split_into_line_groups(&lines, &group_starts)
+    }
+
+    /// Splits the main text on line boundaries into slides with at most `max_chars` characters
+    /// each, counting the line breaks between the lines of a slide. Whole lines are packed
+    /// greedily, a single line longer than `max_chars` is put on a slide of its own.
+    /// Meta text, spoiler text and chord annotations are handled like in
+    /// [`SingleLanguageMainContentSlide::split_by_lines`].
+    /// A slide which already fits (or a `max_chars` of zero) returns a single-element vector.
+    pub fn split_by_chars(&self, max_chars: usize) -> Vec<SingleLanguageMainContentSlide> {
+        let lines: Vec<&str> = self.main_text.lines().collect();
+        if max_chars == 0 || lines.join("\n").chars().count() <= max_chars {
+            return vec![self.clone()];
+        }
+
+        let mut group_starts = Vec::new();
+        let mut group_chars = 0;
+        for (index, line) in lines.iter().enumerate() {
+            let line_chars = line.chars().count();
+            if group_starts.is_empty() || group_chars + 1 + line_chars > max_chars {
+                group_starts.push(index);
+                group_chars = line_chars;
+            } else {
+                group_chars += 1 + line_chars;
+            }
+        }
+        self.split_into_line_groups(&lines, &group_starts)
+    }
+
+    /// Creates one slide per group of consecutive lines, each group starting at the given line index
+    fn split_into_line_groups(
+        &self,
+        lines: &[&str],
+        group_starts: &[usize],
+    ) -> Vec<SingleLanguageMainContentSlide> {
+        let line_starts = self.line_starts();
+        group_starts
+            .iter()
+            .enumerate()
+            .map(|(index, &first_line)| {
+                let end_line = group_starts.get(index + 1).copied().unwrap_or(lines.len());
+                SingleLanguageMainContentSlide {
+                    main_text: lines[first_line..end_line].join("\n"),
+                    spoiler_text: if index + 1 == group_starts.len() {
+                        self.spoiler_text.clone()
+                    } else {
+                        None
+                    },
+                    chords: self.chords.as_ref().map(|chords| {
+                        let start = line_starts[first_line];
+                        let end = line_starts.get(end_line).copied().unwrap_or(usize::MAX);
+                        chords
+                            .iter()
+                            .filter(|chord| chord.char_offset >= start && chord.char_offset < end)
+                            .map(|chord| ChordAnnotation {
+                                char_offset: chord.char_offset - start,
+                                chord: chord.chord.clone(),
+                            })
+                            .collect()
+                    }),
+                    ..self.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct MultiLanguageMainContentSlide {
+    pub main_text_list: Vec<String>,
+    pub spoiler_text_vector: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta_text: Option<String>,
+    /// The language tags (BCP 47, e.g. "en" or "de") of the entries in `main_text_list`
+    #[serde(default)]
+    pub language_codes: Vec<String>,
+    /// The alignment of the main texts, renderers use their own default if it is not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alignment: Option<TextAlignment>,
+    /// The writing directions of the entries in `main_text_list`, may be empty if unknown
+    #[serde(default)]
+    pub directions: Vec<TextDirection>,
+    /// The meta texts of the entries in `main_text_list` (e.g. language-specific copyright
+    /// lines), `meta_text` is used for any language without an entry
+    #[serde(default)]
+    pub meta_text_list: Vec<String>,
+    /// The index of the language to show when there is only space for one language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_language_index: Option<usize>,
+    /// The label of the song section (e.g. "Verse 1" or "Chorus") shown to the operator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section_label: Option<String>,
+}
+
+impl MultiLanguageMainContentSlide {
+    fn new(
+        main_text_list: Vec<String>,
+        spoiler_text_vector: Vec<String>,
+        meta_text: Option<String>,
+    ) -> Self {
+        Self {
+            main_text_list,
+            spoiler_text_vector: spoiler_text_vector
+                .into_iter()
+                .filter(|s| !s.trim().is_empty())
+                .collect(),
+            meta_text: meta_text.filter(|v| !v.trim().is_empty()),
+            language_codes: Vec::new(),
+            alignment: None,
+            directions: Vec::new(),
+            section_label: None,
+            meta_text_list: Vec::new(),
+            primary_language_index: None,
+        }
+    }
+
+    pub fn with_section(mut self, section: SectionKind) -> Self {
+        self.section_label = Some(section.to_string());
+        self
+    }
+
+    /// Returns the kind of song section given by the section label
+    pub fn section_kind(&self) -> Option<SectionKind> {
+        self.section_label.as_deref().map(SectionKind::parse)
+    }
+
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Adds a language to the slide, keeping the main texts, spoilers, directions and language
+    /// codes aligned. A missing spoiler is stored as an empty string. If other languages have
+    /// a direction, the new language is stored as left-to-right.
+    pub fn add_language(&mut self, code: String, main: String, spoiler: Option<String>) {
+        self.add_language_with_direction(code, main, spoiler, None);
+    }
+
+    /// Adds a language with its writing direction to the slide, see
+    /// [`MultiLanguageMainContentSlide::add_language`]. As soon as any language has a direction,
+    /// missing directions are stored as left-to-right.
+    pub fn add_language_with_direction(
+        &mut self,
+        code: String,
+        main: String,
+        spoiler: Option<String>,
+        direction: Option<TextDirection>,
+    ) {
+        // Spoilers may have been filtered on construction, so pad them to keep the alignment
+        if self.spoiler_text_vector.len() < self.main_text_list.len() {
+            self.spoiler_text_vector
+                .resize(self.main_text_list.len(), String::new());
+        }
+        if direction.is_some() || !self.directions.is_empty() {
+            self.directions
+                .resize(self.main_text_list.len(), TextDirection::Ltr);
+            self.directions
+                .push(direction.unwrap_or(TextDirection::Ltr));
+        }
+        self.language_codes.push(code.trim().to_string());
+        self.main_text_list.push(main.trim().to_string());
+        self.spoiler_text_vector
+            .push(spoiler.map(|s| s.trim().to_string()).unwrap_or_default());
+    }
+
+    /// Removes the language with the given code together with its main text and spoiler.
+    /// Returns whether a language was removed.
+    pub fn remove_language(&mut self, code: &str) -> bool {
+        let Some(index) = self.language_codes.iter().position(|c| c == code) else {
+            return false;
+        };
+        self.language_codes.remove(index);
+        if index < self.main_text_list.len() {
+            self.main_text_list.remove(index);
+        }
+        if index < self.spoiler_text_vector.len() {
+            self.spoiler_text_vector.remove(index);
+        }
+        if index < self.directions.len() {
+            self.directions.remove(index);
+        }
+        if index < self.meta_text_list.len() {
+            self.meta_text_list.remove(index);
+        }
+        self.primary_language_index = match self.primary_language_index {
+            Some(primary) if primary == index => None,
+            Some(primary) if primary > index => Some(primary - 1),
+            primary => primary,
+        };
+        true
+    }
+
+    /// Reorders the languages with all their texts to the given order of language codes.
+    /// The order has to contain exactly the language codes of the slide.
+    /// Missing spoilers and meta texts are padded with empty strings before the reordering,
+    /// missing directions are padded with left-to-right if any direction is set.
+    pub fn reorder_languages(&mut self, order: &[String]) -> Result<(), LanguageError> {
+        if !self.languages_aligned() {
+            return Err(LanguageError::Misaligned);
+        }
+        if order.len() != self.language_codes.len() {
+            return Err(LanguageError::OrderMismatch);
+        }
+        let mut used = vec![false; self.language_codes.len()];
+        let mut permutation = Vec::with_capacity(order.len());
+        for code in order {
+            let index = (0..self.language_codes.len())
+                .find(|&index| !used[index] && self.language_codes[index] == *code)
+                .ok_or(LanguageError::OrderMismatch)?;
+            used[index] = true;
+            permutation.push(index);
+        }
+
+        let language_count = self.language_codes.len();
+        if self.spoiler_text_vector.len() < language_count {
+            self.spoiler_text_vector
+                .resize(language_count, String::new());
+        }
+        if self.meta_text_list.len() < language_count {
+            self.meta_text_list.resize(language_count, String::new());
+        }
+        if !self.directions.is_empty() && self.directions.len() < language_count {
+            self.directions.resize(language_count, TextDirection::Ltr);
+        }
+        reorder(&mut self.language_codes, &permutation);
+        reorder(&mut self.main_text_list, &permutation);
+        reorder(&mut self.spoiler_text_vector, &permutation);
+        reorder(&mut self.meta_text_list, &permutation);
+        if !self.directions.is_empty() {
+            reorder(&mut self.directions, &permutation);
+        }
+        if let Some(primary) = self.primary_language_index
+            && primary < language_count
+        {
+            self.primary_language_index = permutation.iter().position(|&i| i == primary);
+        }
+        Ok(())
+    }
+
+    /// Returns the main text of the primary language, or the first main text if no
+    /// (or an out-of-range) primary language is set
+    pub fn primary_main_text(&self) -> Option<&str> {
+        self.primary_language_index
+            .and_then(|index| self.main_text_list.get(index))
+            .or(self.main_text_list.first())
+            .map(String::as_str)
+    }
+
+    /// Returns the main text of the given language
+    pub fn language_line(&self, code: &str) -> Option<&str> {
+        self.language_codes
+            .iter()
+            .position(|c| c == code)
+            .and_then(|index| self.main_text_list.get(index))
+            .map(String::as_str)
+    }
+
+    /// Creates a single-language slide with the main text, spoiler and meta text of the given
+    /// language. Returns `None` if the slide does not contain the language.
+    /// Spoilers are only taken over if they are aligned with the main texts.
+    pub fn to_single_language(&self, code: &str) -> Option<SingleLanguageMainContentSlide> {
+        let index = self.language_codes.iter().position(|c| c == code)?;
+        let main_text = self.main_text_list.get(index)?;
+        let spoiler_text = if self.spoiler_text_vector.len() == self.main_text_list.len() {
+            self.spoiler_text_vector
+                .get(index)
+                .filter(|t| !t.is_empty())
+                .cloned()
+        } else {
+            None
+        };
+        Some(SingleLanguageMainContentSlide {
+            main_text: main_text.clone(),
+            spoiler_text,
+            meta_text: self.meta_text_for(code).map(str::to_string),
+            alignment: self.alignment,
+            direction: self.directions.get(index).copied(),
+            section_label: self.section_label.clone(),
+            chords: None,
+        })
+    }
+
+    /// Returns the meta text of the given language, falling back to the shared meta text
+    /// if the language has no (or an empty) meta text of its own
+    pub fn meta_text_for(&self, code: &str) -> Option<&str> {
+        self.language_codes
+            .iter()
+            .position(|c| c == code)
+            .and_then(|index| self.meta_text_list.get(index))
+            .map(String::as_str)
+            .filter(|meta_text| !meta_text.is_empty())
+            .or(self.meta_text.as_deref())
+    }
+
+    /// Checks whether the main text at the given index is written right-to-left
+    pub fn is_rtl_at(&self, index: usize) -> bool {
+        self.directions.get(index) == Some(&TextDirection::Rtl)
+    }
+
+    /// Checks whether every main text has exactly one language code
+    pub fn languages_aligned(&self) -> bool {
+        self.main_text_list.len() == self.language_codes.len()
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct EmptySlide {
+    pub black_background: bool,
+}
+
+/// An empty slide defaults to a black background
+impl Default for EmptySlide {
+    fn default() -> Self {
+        Self {
+            black_background: true,
+        }
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct TitleSlide {
+    pub title_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta_text: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct VideoSlide {
+    pub video_path: String,
+    pub loop_playback: bool,
+    pub autoplay: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct CountdownSlide {
+    pub target_seconds: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    pub black_background: bool,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct SimplePictureSlide {
+    pub picture_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimensions: Option<(u32, u32)>,
+}
+
+impl SimplePictureSlide {
+    pub fn new(picture_path: String) -> Self {
+        Self {
+            picture_path,
+            alt_text: None,
+            dimensions: None,
+        }
+    }
+
+    pub fn with_alt_text(mut self, alt_text: String) -> Self {
+        self.alt_text = Some(alt_text);
+        self
+    }
+
+    /// Sets the intrinsic dimensions (width, height) of the picture in pixels
+    pub fn with_dimensions(mut self, width: u32, height: u32) -> Self {
+        self.dimensions = Some((width, height));
+        self
+    }
+}
+
+/// A summary of the content of a presentation chapter, see [`PresentationChapter::stats`]
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ChapterStats {
+    pub slide_count: usize,
+
+    /// The number of single- and multi-language content slides
+    pub content_slide_count: usize,
+
+    /// The number of words of all visible text, see [`SlideContent::word_count`]
+    pub word_count: usize,
+
+    pub has_title: bool,
+
+    pub has_pictures: bool,
+
+    /// The language codes of all multi-language slides in the order of their first occurrence
+    pub languages: Vec<String>,
+}
+
+// --- Media Resolution ---
+
+/// Resolves the abstract media references of slides to file paths.
+/// Host crates implement it for their media type to locate the files for rendering,
+/// it is also implemented for closures taking a media reference.
+pub trait MediaResolver<M> {
+    /// Returns the path of the given media, or `None` if it cannot be resolved
+    fn resolve(&self, media: &M) -> Option<PathBuf>;
+}
+
+impl<M, F> MediaResolver<M> for F
+where
+    F: Fn(&M) -> Option<PathBuf>,
+{
+    fn resolve(&self, media: &M) -> Option<PathBuf> {
+        self(media)
+    }
+}
+
+// --- Presentation Metadata ---
+
+/// The transition which is used when a slide is shown
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub enum Transition {
+    /// The slide appears immediately
+    Cut,
+
+    /// The slide fades in over the given duration
+    Fade { millis: u32 },
+
+    /// The slide moves in from the given direction over the given duration
+    Slide { direction: Direction, millis: u32 },
+}
+
+/// The direction of a sliding transition
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// The horizontal alignment of the text on a slide
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub enum TextAlignment {
+    Left,
+    Center,
+    Right,
+    Justify,
+}
+
+/// The writing direction of a text
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub enum TextDirection {
+    /// Left-to-right, e.g. English or German
+    Ltr,
+
+    /// Right-to-left, e.g. Hebrew or Arabic
+    Rtl,
+}
+
+/// A chord shown above the main text of a slide
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub struct ChordAnnotation {
+    /// The offset in characters (not bytes) within the main text, line breaks included
+    pub char_offset: usize,
+
+    /// The name of the chord, e.g. "G7"
+    pub chord: String,
+}
+
+/// The kind of a song section, parsed from a section label
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SectionKind {
+    /// A numbered verse
+    Verse(u32),
+
+    Chorus,
+
+    Bridge,
+
+    /// Any other section with its label
+    Other(String),
+}
+
+impl SectionKind {
+    /// Parses a section label like "Verse 2", "Chorus" or "Bridge" case-insensitively.
+    /// The German labels "Strophe" and "Refrain" are understood as well, a verse without a
+    /// number is the first verse. Any other label is kept as `Other`.
+    pub fn parse(label: &str) -> Self {
+        let label = label.trim();
+        let lowercase = label.to_lowercase();
+        let mut words = lowercase.split_whitespace();
+        let kind = words.next().unwrap_or_default();
+        let number = words.next();
+        if words.next().is_some() {
+            return SectionKind::Other(label.to_string());
+        }
+        match (kind, number) {
+            ("verse" | "strophe", None) => SectionKind::Verse(1),
+            ("verse" | "strophe", Some(number)) => match number.parse() {
+                Ok(number) => SectionKind::Verse(number),
+                Err(_) => SectionKind::Other(label.to_string()),
+            },
+            ("chorus" | "refrain", None) => SectionKind::Chorus,
+            ("bridge", None) => SectionKind::Bridge,
+            _ => SectionKind::Other(label.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for SectionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SectionKind::Verse(number) => write!(f, "Verse {}", number),
+            SectionKind::Chorus => write!(f, "Chorus"),
+            SectionKind::Bridge => write!(f, "Bridge"),
+            SectionKind::Other(label) => write!(f, "{}", label),
+        }
+    }
+}
+
+/// The background of a slide
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(::bincode::Encode, ::bincode::Decode))]
+pub enum Background {
+    /// A solid color given as red, green, blue and alpha components
+    Color { rgba: [u8; 4] },
+
+    /// A picture at the given path covering the whole slide
+    Image { path: String },
+}
+
+// --- Error Definitions ---
+
+/// The error returned when merging two presentation chapters fails
+#[derive(Clone, PartialEq, Debug)]
+pub enum MergeError {
+    /// The chapters are linked to different entities
+    EntityMismatch,
+}
+
+impl std::fmt::Display for MergeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeError::EntityMismatch => {
+                write!(f, "the chapters are linked to different entities")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// The error returned when a slide does not contain the content it requires
+#[derive(Clone, PartialEq, Debug)]
+pub enum SlideValidationError {
+    /// The main text of a content slide is empty
+    EmptyMainText,
+
+    /// The title text of a title slide is empty
+    EmptyTitle,
+
+    /// The picture path of a picture slide is empty
+    EmptyPicturePath,
+}
+
+impl std::fmt::Display for SlideValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlideValidationError::EmptyMainText => write!(f, "the main text is empty"),
+            SlideValidationError::EmptyTitle => write!(f, "the title is empty"),
+            SlideValidationError::EmptyPicturePath => write!(f, "the picture path is empty"),
+        }
+    }
+}
+
+impl std::error::Error for SlideValidationError {}
+
+/// The error returned when a slide within a presentation chapter is invalid
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChapterValidationError {
+    /// The index of the first invalid slide within the chapter
+    pub slide_index: usize,
+
+    /// The validation error of the slide
+    pub error: SlideValidationError,
+}
+
+impl std::fmt::Display for ChapterValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "slide {} is invalid: {}", self.slide_index, self.error)
+    }
+}
+
+impl std::error::Error for ChapterValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// The error returned when a ChordPro song cannot be imported
+#[derive(Clone, PartialEq, Debug)]
+pub enum ChordProError {
+    /// A directive opened with `{` is not closed on the same line (1-based)
+    UnclosedDirective { line: usize },
+
+    /// A chord opened with `[` is not closed on the same line (1-based)
+    UnclosedChord { line: usize },
+
+    /// A section is started on the given line (1-based) while another one is still open
+    NestedSection { line: usize },
+
+    /// A section is ended on the given line (1-based) without having been started
+    UnexpectedSectionEnd { line: usize },
+
+    /// A section is still open at the end of the input
+    UnterminatedSection,
+}
+
+impl std::fmt::Display for ChordProError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordProError::UnclosedDirective { line } => {
+                write!(f, "the directive on line {} is not closed", line)
+            }
+            ChordProError::UnclosedChord { line } => {
+                write!(f, "the chord on line {} is not closed", line)
+            }
+            ChordProError::NestedSection { line } => {
+                write!(
+                    f,
+                    "the section on line {} starts within another section",
+                    line
+                )
+            }
+            ChordProError::UnexpectedSectionEnd { line } => {
+                write!(f, "line {} ends a section which has not been started", line)
+            }
+            ChordProError::UnterminatedSection => {
+                write!(f, "a section is not ended before the end of the input")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChordProError {}
+
+/// The error returned when an OpenLyrics document cannot be imported
+#[cfg(feature = "openlyrics")]
+#[derive(Clone, PartialEq, Debug)]
+pub enum OpenLyricsError {
+    /// The document is not well-formed XML, the message describes the problem
+    MalformedXml(String),
+
+    /// The root element of the document is not a `<song>`
+    MissingSong,
+}
+
+#[cfg(feature = "openlyrics")]
+impl std::fmt::Display for OpenLyricsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenLyricsError::MalformedXml(message) => write!(f, "malformed XML: {}", message),
+            OpenLyricsError::MissingSong => write!(f, "the document contains no song"),
+        }
+    }
+}
+
+#[cfg(feature = "openlyrics")]
+impl std::error::Error for OpenLyricsError {}
+
+/// The error returned when the languages of a multi-language slide cannot be changed
+#[derive(Clone, PartialEq, Debug)]
+pub enum LanguageError {
+    /// The given order of language codes does not match the language codes of the slide
+    OrderMismatch,
+
+    /// The main texts are not aligned with the language codes
+    Misaligned,
+}
+
+impl std::fmt::Display for LanguageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanguageError::OrderMismatch => {
+                write!(f, "the order does not match the languages of the slide")
+            }
+            LanguageError::Misaligned => {
+                write!(f, "the main texts are not aligned with the language codes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LanguageError {}
+
+/// A position within a JSON input
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct JsonLocation {
+    /// The line (1-based)
+    pub line: usize,
+
+    /// The column (1-based) within the line
+    pub column: usize,
+
+    /// The offset in bytes from the start of the input
+    pub offset: usize,
+}
+
+/// The error returned when a chapter cannot be loaded
+#[derive(Clone, PartialEq, Debug)]
+pub enum SlideError {
+    /// The input ends before the chapter is complete, e.g. because the file is truncated.
+    /// The offset of the location is the length of the input.
+    UnexpectedEnd { location: JsonLocation },
+
+    /// The input is not valid JSON
+    Syntax {
+        location: JsonLocation,
+        message: String,
+    },
+
+    /// The input is valid JSON but does not describe a chapter.
+    /// `expected_field` names the field if a required one is missing.
+    Data {
+        location: JsonLocation,
+        message: String,
+        expected_field: Option<String>,
+    },
+
+    /// The input could not be read
+    Io { message: String },
+}
+
+impl SlideError {
+    fn from_json(error: serde_json::Error, input: &str) -> Self {
+        let (line, column) = (error.line(), error.column());
+        let line_start: usize = input
+            .split_inclusive('\n')
+            .take(line.saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let location = JsonLocation {
+            line,
+            column,
+            offset: (line_start + column.saturating_sub(1)).min(input.len()),
+        };
+        // The message of serde_json ends with the location, which is reported separately
+        let message = error.to_string();
+        let message = message
+            .strip_suffix(&format!(" at line {} column {}", line, column))
+            .unwrap_or(&message)
+            .to_string();
+        match error.classify() {
+            serde_json::error::Category::Eof => SlideError::UnexpectedEnd {
+                location: JsonLocation {
+                    offset: input.len(),
+                    ..location
+                },
+            },
+            serde_json::error::Category::Syntax => SlideError::Syntax { location, message },
+            serde_json::error::Category::Data => SlideError::Data {
+                location,
+                expected_field: message
+                    .strip_prefix("missing field `")
+                    .and_then(|rest| rest.split_once('`'))
+                    .map(|(field, _)| field.to_string()),
+                message,
+            },
+            serde_json::error::Category::Io => SlideError::Io { message },
+        }
+    }
+}
+
+impl std::fmt::Display for SlideError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlideError::UnexpectedEnd { location } => write!(
+                f,
+                "unexpected end of input at line {}, column {}",
+                location.line, location.column
+            ),
+            SlideError::Syntax { location, message } => write!(
+                f,
+                "invalid JSON at line {}, column {}: {}",
+                location.line, location.column, message
+            ),
+            SlideError::Data {
+                location, message, ..
+            } => write!(
+                f,
+                "invalid chapter at line {}, column {}: {}",
+                location.line, location.column, message
+            ),
+            SlideError::Io { message } => write!(f, "the input could not be read: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SlideError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mocking your actual domain objects
+    #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+    pub struct Song {
+        pub id: u32,
+        pub title: String,
+    }
+
+    #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+    pub struct SongFile {
+        pub path: String,
+    }
+
+    // Creating type aliases makes the code much cleaner in the rest of your app
+    type SongPresentation = PresentationChapter<Song, SongFile>;
+
+    #[test]
+    fn test_generic_presentation_creation() {
+        let song = Song {
+            id: 1,
+            title: "Amazing Grace".to_string(),
+        };
+        let file = SongFile {
+            path: "/assets/grace.mp3".to_string(),
+        };
+
+        let slide = Slide::new_content_slide(
+            "Amazing grace, how sweet the sound".to_string(),
+            None,
+            Some("Verse 1".to_string()),
+        )
+        .with_media(file.clone());
+
+        let presentation = SongPresentation::new(vec![slide], LinkedEntity::Source(song));
+
+        assert_eq!(presentation.len(), 1);
+        assert!(presentation.slides[0].linked_file.is_some());
+
+        // Verify we can access our custom SongFile data
+        if let Some(ref media) = presentation.slides[0].linked_file {
+            assert_eq!(media.path, "/assets/grace.mp3");
+        }
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let song = Song {
+            id: 42,
+            title: "Test Song".to_string(),
+        };
+        let presentation = PresentationChapter::<Song, SongFile>::new(
+            vec![Slide::new_title_slide("Title".to_string(), None)],
+            LinkedEntity::Source(song),
+        );
+
+        // Serialize to JSON
+        let json = serde_json::to_string(&presentation).unwrap();
+
+        // Deserialize back
+        let deserialized: PresentationChapter<Song, SongFile> =
+            serde_json::from_str(&json).unwrap();
+
+        assert_eq!(presentation, deserialized);
+        if let LinkedEntity::Source(s) = &deserialized.linked_entity {
+            assert_eq!(s.id, 42);
+        }
+    }
+
+    #[test]
+    fn test_different_generic_types() {
+        // This proves the library isn't tied to 'Song' anymore.
+        // We can use simple Strings for everything.
+        let presentation = PresentationChapter::<String, String>::new(
+            vec![Slide::new_empty_slide(true).with_media("background.png".into())],
+            LinkedEntity::Title("Simple Show".into()),
+        );
+
+        assert_eq!(
+            presentation.linked_entity,
             LinkedEntity::Title("Simple Show".into())
         );
     }
+
+    #[test]
+    fn test_presentation_serialization_roundtrip() {
+        let song = Song {
+            id: 7,
+            title: "Amazing Grace".to_string(),
+        };
+        let mut presentation = Presentation::<Song, SongFile>::new(
+            vec![PresentationChapter::new(
+                vec![
+                    Slide::new_title_slide("Amazing Grace".to_string(), None),
+                    Slide::new_content_slide(
+                        "Amazing grace, how sweet the sound".to_string(),
+                        None,
+                        None,
+                    ),
+                ],
+                LinkedEntity::Source(song),
+            )],
+            Some("Sunday Service".to_string()),
+        );
+        presentation.push_chapter(PresentationChapter::new(
+            vec![Slide::new_empty_slide(true)],
+            LinkedEntity::Title("Announcements".to_string()),
+        ));
+
+        assert_eq!(presentation.chapters.len(), 2);
+        assert_eq!(presentation.total_slide_count(), 3);
+
+        let json = serde_json::to_string(&presentation).unwrap();
+        let deserialized: Presentation<Song, SongFile> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(presentation, deserialized);
+        assert_eq!(
+            deserialized.chapters[1].linked_entity,
+            LinkedEntity::Title("Announcements".to_string())
+        );
+    }
+
+    #[test]
+    fn test_multi_language_content_slide_filters_spoilers() {
+        let slide = Slide::<String>::new_multi_language_content_slide(
+            vec![
+                "  Amazing grace ".to_string(),
+                "Erstaunliche Gnade".to_string(),
+            ],
+            vec![
+                " How sweet the sound ".to_string(),
+                "   ".to_string(),
+                String::new(),
+                "Wie süß der Klang".to_string(),
+            ],
+            Some("  ".to_string()),
+        );
+
+        match &slide.slide_content {
+            SlideContent::MultiLanguageMainContent(s) => {
+                assert_eq!(
+                    s.main_text_list,
+                    vec!["Amazing grace", "Erstaunliche Gnade"]
+                );
+                assert_eq!(
+                    s.spoiler_text_vector,
+                    vec!["How sweet the sound", "Wie süß der Klang"]
+                );
+                assert_eq!(s.meta_text, None);
+            }
+            other => panic!("Unexpected slide content: {:?}", other),
+        }
+        assert!(slide.has_spoiler());
+        assert!(!slide.has_meta_text());
+    }
+
+    #[test]
+    fn test_chapter_iteration() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Title".to_string(), None),
+                Slide::new_content_slide("Verse".to_string(), None, None),
+                Slide::new_empty_slide(false),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        let references: Vec<&Slide<String>> = chapter.iter().collect();
+        assert_eq!(references.len(), chapter.len());
+
+        let mut count = 0;
+        for slide in &chapter {
+            assert_eq!(slide.uuid(), chapter.slides[count].uuid());
+            count += 1;
+        }
+        assert_eq!(count, chapter.len());
+
+        let expected: Vec<Uuid> = chapter.slides.iter().map(|s| s.uuid()).collect();
+        let owned: Vec<Slide<String>> = chapter.into_iter().collect();
+        assert_eq!(owned.iter().map(|s| s.uuid()).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_merge_chapters_with_same_entity() {
+        let first = PresentationChapter::<String, String>::new(
+            vec![Slide::new_content_slide("Verse 1".to_string(), None, None)],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        let second = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_content_slide("Verse 2".to_string(), None, None),
+                Slide::new_content_slide("Verse 3".to_string(), None, None),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        let expected: Vec<Uuid> = first.iter().chain(&second).map(|s| s.uuid()).collect();
+
+        let merged = first.merge(second).unwrap();
+
+        assert_eq!(
+            merged.slides.iter().map(|s| s.uuid()).collect::<Vec<_>>(),
+            expected
+        );
+        assert_eq!(
+            merged.linked_entity,
+            LinkedEntity::Title("Song".to_string())
+        );
+    }
+
+    #[test]
+    fn test_merge_chapters_with_different_entities() {
+        let first = PresentationChapter::<String, String>::new(
+            vec![Slide::new_content_slide("Verse 1".to_string(), None, None)],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        let second = PresentationChapter::<String, String>::new(
+            vec![Slide::new_content_slide("Verse 1".to_string(), None, None)],
+            LinkedEntity::Source("another-song".to_string()),
+        );
+
+        assert_eq!(first.merge(second), Err(MergeError::EntityMismatch));
+    }
+
+    #[test]
+    fn test_slide_validation() {
+        assert_eq!(
+            Slide::<String>::new_content_slide("   ".to_string(), None, None).validate(),
+            Err(SlideValidationError::EmptyMainText)
+        );
+        assert_eq!(
+            Slide::<String>::new_title_slide(String::new(), None).validate(),
+            Err(SlideValidationError::EmptyTitle)
+        );
+        assert_eq!(Slide::<String>::new_empty_slide(true).validate(), Ok(()));
+        assert_eq!(
+            Slide::<String>::new_content_slide("Verse".to_string(), None, None).validate(),
+            Ok(())
+        );
+
+        let picture = Slide::<String>::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new(String::new()),
+        ));
+        assert_eq!(
+            picture.validate(),
+            Err(SlideValidationError::EmptyPicturePath)
+        );
+    }
+
+    #[test]
+    fn test_chapter_validation_reports_first_invalid_slide() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Title".to_string(), None),
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide(" ".to_string(), None, None),
+                Slide::new_title_slide(String::new(), None),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        assert_eq!(
+            chapter.validate(),
+            Err(ChapterValidationError {
+                slide_index: 2,
+                error: SlideValidationError::EmptyMainText,
+            })
+        );
+    }
+
+    fn verse_with_lines(line_count: usize) -> SingleLanguageMainContentSlide {
+        SingleLanguageMainContentSlide::new(
+            (1..=line_count)
+                .map(|i| format!("Line {}", i))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Some("Next verse".to_string()),
+            Some("Verse 1".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_split_by_lines_fitting_slide() {
+        let verse = verse_with_lines(3);
+        assert_eq!(verse.split_by_lines(4), vec![verse.clone()]);
+        assert_eq!(verse.split_by_lines(3), vec![verse]);
+    }
+
+    #[test]
+    fn test_split_by_lines_exact_multiple() {
+        let chunks = verse_with_lines(4).split_by_lines(2);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].main_text, "Line 1\nLine 2");
+        assert_eq!(chunks[1].main_text, "Line 3\nLine 4");
+        assert_eq!(chunks[0].spoiler_text, None);
+        assert_eq!(chunks[1].spoiler_text, Some("Next verse".to_string()));
+        assert!(
+            chunks
+                .iter()
+                .all(|c| c.meta_text == Some("Verse 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_split_by_lines_remainder() {
+        let chunks = verse_with_lines(5).split_by_lines(2);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[2].main_text, "Line 5");
+        assert_eq!(chunks[1].spoiler_text, None);
+        assert_eq!(chunks[2].spoiler_text, Some("Next verse".to_string()));
+        assert!(
+            chunks
+                .iter()
+                .all(|c| c.meta_text == Some("Verse 1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_picture_slide_builder() {
+        let picture = SimplePictureSlide::new("/assets/cross.png".to_string())
+            .with_alt_text("A wooden cross".to_string())
+            .with_dimensions(1920, 1080);
+
+        assert_eq!(picture.picture_path, "/assets/cross.png");
+        assert_eq!(picture.alt_text, Some("A wooden cross".to_string()));
+        assert_eq!(picture.dimensions, Some((1920, 1080)));
+    }
+
+    #[test]
+    fn test_picture_slide_deserializes_without_new_fields() {
+        let json = r#"{"picture_path":"/assets/cross.png"}"#;
+        let picture: SimplePictureSlide = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            picture,
+            SimplePictureSlide::new("/assets/cross.png".to_string())
+        );
+    }
+
+    #[test]
+    fn test_video_slide_serialization_roundtrip() {
+        let slide = Slide::<String>::new_video_slide("/assets/intro.mp4".to_string(), true, false);
+
+        assert!(!slide.has_spoiler());
+        assert!(!slide.has_meta_text());
+
+        let json = serde_json::to_string(&slide).unwrap();
+        let deserialized: Slide<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(slide, deserialized);
+        assert_eq!(
+            deserialized.slide_content,
+            SlideContent::Video(VideoSlide {
+                video_path: "/assets/intro.mp4".to_string(),
+                loop_playback: true,
+                autoplay: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_countdown_slide() {
+        let slide = Slide::<String>::new_countdown_slide(300, Some("  ".to_string()));
+
+        match &slide.slide_content {
+            SlideContent::Countdown(countdown) => {
+                assert_eq!(countdown.target_seconds, 300);
+                assert_eq!(countdown.message, None);
+                assert!(countdown.black_background);
+            }
+            other => panic!("Unexpected slide content: {:?}", other),
+        }
+        assert!(!slide.has_meta_text());
+    }
+
+    #[test]
+    fn test_countdown_default_message() {
+        let countdown: CountdownSlide =
+            serde_json::from_str(r#"{"target_seconds": 300, "black_background": true}"#).unwrap();
+
+        assert_eq!(countdown.message, None);
+        assert!(
+            !serde_json::to_string(&countdown)
+                .unwrap()
+                .contains("message")
+        );
+    }
+
+    #[test]
+    fn test_countdown_slide_serialization_roundtrip() {
+        let slide = Slide::<String>::new_countdown_slide(
+            600,
+            Some(" The service starts soon ".to_string()),
+        );
+
+        let json = serde_json::to_string(&slide).unwrap();
+        let deserialized: Slide<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(slide, deserialized);
+        assert!(json.contains("The service starts soon"));
+    }
+
+    #[test]
+    fn test_transition_serialization_roundtrip() {
+        let slide = Slide::<String>::new_content_slide("Verse".to_string(), None, None)
+            .with_transition(Transition::Fade { millis: 500 });
+
+        let json = serde_json::to_string(&slide).unwrap();
+        let deserialized: Slide<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(slide, deserialized);
+        assert_eq!(
+            deserialized.transition,
+            Some(Transition::Fade { millis: 500 })
+        );
+    }
+
+    #[test]
+    fn test_slide_without_transition_deserializes() {
+        let json = r#"{
+            "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+            "slide_content": {"Empty": {"black_background": true}},
+            "linked_file": null
+        }"#;
+        let slide: Slide<String> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(slide.transition, None);
+    }
+
+    #[test]
+    fn test_total_duration_of_fully_timed_chapter() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Announcements".to_string(), None).with_duration(5000),
+                Slide::new_content_slide("Church picnic".to_string(), None, None)
+                    .with_duration(8000),
+            ],
+            LinkedEntity::Title("Announcements".to_string()),
+        );
+
+        assert_eq!(chapter.total_duration_millis(), Some(13000));
+    }
+
+    #[test]
+    fn test_total_duration_of_partially_timed_chapter() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Announcements".to_string(), None).with_duration(5000),
+                Slide::new_content_slide("Church picnic".to_string(), None, None),
+            ],
+            LinkedEntity::Title("Announcements".to_string()),
+        );
+
+        assert_eq!(chapter.total_duration_millis(), None);
+    }
+
+    #[test]
+    fn test_slide_display() {
+        // A media type without a Display implementation
+        let file = SongFile {
+            path: "/assets/grace.mp3".to_string(),
+        };
+
+        let content = Slide::new_content_slide(
+            "Amazing grace,\nhow sweet the sound".to_string(),
+            Some("That saved a wretch like me".to_string()),
+            Some("Verse 1".to_string()),
+        )
+        .with_media(file);
+        assert_eq!(content.to_string(), "Amazing grace,\nhow sweet the sound");
+
+        let title = Slide::<SongFile>::new_title_slide("Amazing Grace".to_string(), None);
+        assert_eq!(title.to_string(), "Amazing Grace");
+
+        let multi_language = Slide::<SongFile>::new_multi_language_content_slide(
+            vec![
+                "Amazing grace".to_string(),
+                "Erstaunliche Gnade".to_string(),
+            ],
+            vec![],
+            None,
+        );
+        assert_eq!(
+            multi_language.to_string(),
+            "Amazing grace / Erstaunliche Gnade"
+        );
+
+        let picture = Slide::<SongFile>::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new("/assets/cross.png".to_string()),
+        ));
+        assert_eq!(picture.to_string(), "[picture: /assets/cross.png]");
+
+        assert_eq!(
+            Slide::<SongFile>::new_empty_slide(true).to_string(),
+            "[empty]"
+        );
+        assert_eq!(
+            Slide::<SongFile>::new_video_slide("/assets/intro.mp4".to_string(), false, true)
+                .to_string(),
+            "[video: /assets/intro.mp4]"
+        );
+        assert_eq!(
+            Slide::<SongFile>::new_countdown_slide(300, None).to_string(),
+            "[countdown: 300s]"
+        );
+    }
+
+    #[test]
+    fn test_plain_text_export() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Amazing Grace".to_string(),
+                    Some("John Newton".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Amazing grace, how sweet the sound\nThat saved a wretch like me".to_string(),
+                    Some("'Twas grace that taught my heart to fear".to_string()),
+                    Some("Verse 1".to_string()),
+                ),
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide(
+                    "'Twas grace that taught my heart to fear\nAnd grace my fears relieved"
+                        .to_string(),
+                    None,
+                    None,
+                ),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        assert_eq!(
+            chapter.to_plain_text(),
+            "Amazing Grace\n\
+             (John Newton)\n\
+             \n\
+             Amazing grace, how sweet the sound\n\
+             That saved a wretch like me\n\
+             (Verse 1)\n\
+             \n\
+             'Twas grace that taught my heart to fear\n\
+             And grace my fears relieved"
+        );
+    }
+
+    #[test]
+    fn test_markdown_export() {
+        let picture = Slide::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new("cross.png".to_string()).with_alt_text("A cross".to_string()),
+        ));
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Amazing Grace".to_string(),
+                    Some("John Newton".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Amazing grace, how sweet the sound".to_string(),
+                    None,
+                    Some("Verse 1".to_string()),
+                ),
+                Slide::new_empty_slide(true),
+                picture,
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        assert_eq!(
+            chapter.to_markdown(),
+            "# Amazing Grace\n\
+             \n\
+             *John Newton*\n\
+             \n\
+             Amazing grace, how sweet the sound\n\
+             \n\
+             *Verse 1*\n\
+             \n\
+             ---\n\
+             \n\
+             ![A cross](cross.png)\n"
+        );
+    }
+
+    #[test]
+    fn test_schema_version_is_serialized() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        let json = serde_json::to_string(&chapter).unwrap();
+        assert!(json.contains(&format!("\"schema_version\":{}", CURRENT_SCHEMA_VERSION)));
+    }
+
+    #[test]
+    fn test_migrate_unversioned_chapter() {
+        let json = r#"{
+            "slides": [{
+                "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+                "slide_content": {"Title": {"title_text": "Amazing Grace", "meta_text": null}},
+                "linked_file": null
+            }],
+            "linked_entity": {"Title": "Amazing Grace"}
+        }"#;
+        let mut chapter: PresentationChapter<String, String> = serde_json::from_str(json).unwrap();
+        assert_eq!(chapter.schema_version, UNVERSIONED_SCHEMA_VERSION);
+
+        chapter.migrate();
+
+        assert_eq!(chapter.schema_version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(chapter.len(), 1);
+    }
+
+    #[test]
+    fn test_migrate_old_version() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        chapter.schema_version = 0;
+
+        chapter.migrate();
+
+        assert_eq!(chapter.schema_version, CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_map_media() {
+        let chapter = PresentationChapter::<Song, String>::new(
+            vec![
+                Slide::new_content_slide("Verse 1".to_string(), None, None)
+                    .with_media("/assets/grace.mp3".to_string()),
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Media("/assets/grace.mp3".to_string()),
+        );
+        let uuids: Vec<Uuid> = chapter.iter().map(|s| s.uuid()).collect();
+
+        let mapped: PresentationChapter<Song, SongFile> =
+            chapter.map_media(|path| SongFile { path });
+
+        assert_eq!(
+            mapped.slides[0].linked_file,
+            Some(SongFile {
+                path: "/assets/grace.mp3".to_string()
+            })
+        );
+        assert_eq!(mapped.slides[1].linked_file, None);
+        assert_eq!(
+            mapped.linked_entity,
+            LinkedEntity::Media(SongFile {
+                path: "/assets/grace.mp3".to_string()
+            })
+        );
+        assert_eq!(mapped.iter().map(|s| s.uuid()).collect::<Vec<_>>(), uuids);
+        assert_eq!(
+            mapped.slides[0].slide_content,
+            Slide::<SongFile>::new_content_slide("Verse 1".to_string(), None, None).slide_content
+        );
+    }
+
+    fn load_song(id: u32) -> Song {
+        Song {
+            id,
+            title: format!("Song {}", id),
+        }
+    }
+
+    #[test]
+    fn test_map_entity_source() {
+        let chapter = PresentationChapter::<u32, String>::new(
+            vec![Slide::new_content_slide("Verse 1".to_string(), None, None)],
+            LinkedEntity::Source(3),
+        );
+
+        let mapped: PresentationChapter<Song, String> = chapter.map_entity(load_song);
+
+        assert_eq!(mapped.linked_entity, LinkedEntity::Source(load_song(3)));
+        assert_eq!(mapped.len(), 1);
+    }
+
+    #[test]
+    fn test_map_entity_keeps_title_and_media() {
+        let title_chapter = PresentationChapter::<u32, String>::new(
+            vec![],
+            LinkedEntity::Title("Announcements".to_string()),
+        );
+        let mapped: PresentationChapter<Song, String> = title_chapter.map_entity(load_song);
+        assert_eq!(
+            mapped.linked_entity,
+            LinkedEntity::Title("Announcements".to_string())
+        );
+
+        let media_chapter = PresentationChapter::<u32, String>::new(
+            vec![],
+            LinkedEntity::Media("/assets/intro.mp4".to_string()),
+        );
+        let mapped: PresentationChapter<Song, String> =
+            media_chapter.map_entity(|_| panic!("Only source entities are mapped"));
+        assert_eq!(
+            mapped.linked_entity,
+            LinkedEntity::Media("/assets/intro.mp4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_slide_content_kind() {
+        assert_eq!(
+            Slide::<String>::new_content_slide("Verse".to_string(), None, None).kind(),
+            SlideContentKind::SingleLanguageMainContent
+        );
+        assert_eq!(
+            Slide::<String>::new_title_slide("Title".to_string(), None).kind(),
+            SlideContentKind::Title
+        );
+        assert_eq!(
+            Slide::<String>::new_multi_language_content_slide(
+                vec!["Verse".to_string()],
+                vec![],
+                None
+            )
+            .kind(),
+            SlideContentKind::MultiLanguageMainContent
+        );
+        assert_eq!(
+            Slide::<String>::new_empty_slide(true).kind(),
+            SlideContentKind::Empty
+        );
+        assert_eq!(
+            Slide::<String>::new_video_slide("intro.mp4".to_string(), false, false).kind(),
+            SlideContentKind::Video
+        );
+        assert_eq!(
+            Slide::<String>::new_countdown_slide(60, None).kind(),
+            SlideContentKind::Countdown
+        );
+        assert_eq!(
+            SlideContent::SimplePicture(SimplePictureSlide::new("cross.png".to_string())).kind(),
+            SlideContentKind::SimplePicture
+        );
+    }
+
+    #[test]
+    fn test_count_by_kind() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Title".to_string(), None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_content_slide("Verse 2".to_string(), None, None),
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide("Verse 3".to_string(), None, None),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        let counts = chapter.count_by_kind();
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&SlideContentKind::Title], 1);
+        assert_eq!(counts[&SlideContentKind::SingleLanguageMainContent], 3);
+        assert_eq!(counts[&SlideContentKind::Empty], 1);
+        assert!(!counts.contains_key(&SlideContentKind::SimplePicture));
+    }
+
+    #[test]
+    fn test_all_text() {
+        let content = Slide::<String>::new_content_slide(
+            "Amazing grace".to_string(),
+            Some("How sweet the sound".to_string()),
+            Some("Verse 1".to_string()),
+        );
+        assert_eq!(
+            content.slide_content.all_text(),
+            vec!["Amazing grace", "How sweet the sound", "Verse 1"]
+        );
+
+        let content_without_extras =
+            Slide::<String>::new_content_slide("Amazing grace".to_string(), None, None);
+        assert_eq!(
+            content_without_extras.slide_content.all_text(),
+            vec!["Amazing grace"]
+        );
+
+        let title = Slide::<String>::new_title_slide(
+            "Amazing Grace".to_string(),
+            Some("John Newton".to_string()),
+        );
+        assert_eq!(
+            title.slide_content.all_text(),
+            vec!["Amazing Grace", "John Newton"]
+        );
+
+        let multi_language = Slide::<String>::new_multi_language_content_slide(
+            vec![
+                "Amazing grace".to_string(),
+                "Erstaunliche Gnade".to_string(),
+            ],
+            vec!["How sweet the sound".to_string()],
+            Some("Verse 1".to_string()),
+        );
+        assert_eq!(
+            multi_language.slide_content.all_text(),
+            vec![
+                "Amazing grace",
+                "Erstaunliche Gnade",
+                "How sweet the sound",
+                "Verse 1"
+            ]
+        );
+
+        let countdown = Slide::<String>::new_countdown_slide(60, Some("Starting soon".to_string()));
+        assert_eq!(countdown.slide_content.all_text(), vec!["Starting soon"]);
+
+        let picture = SlideContent::SimplePicture(
+            SimplePictureSlide::new("cross.png".to_string()).with_alt_text("A cross".to_string()),
+        );
+        assert!(picture.all_text().is_empty());
+        assert!(
+            Slide::<String>::new_empty_slide(true)
+                .slide_content
+                .all_text()
+                .is_empty()
+        );
+        assert!(
+            Slide::<String>::new_video_slide("intro.mp4".to_string(), false, false)
+                .slide_content
+                .all_text()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_find_slides_containing() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Amazing Grace".to_string(),
+                    Some("John Newton".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Amazing grace, how sweet the sound".to_string(),
+                    None,
+                    Some("Verse 1".to_string()),
+                ),
+                Slide::new_multi_language_content_slide(
+                    vec![
+                        "Through many dangers".to_string(),
+                        "Durch viele Gefahren".to_string(),
+                    ],
+                    vec![],
+                    Some("Verse 3".to_string()),
+                ),
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        assert_eq!(chapter.find_slides_containing("AMAZING"), vec![0, 1]);
+        assert_eq!(chapter.find_slides_containing("sweet the"), vec![1]);
+        assert_eq!(chapter.find_slides_containing("newton"), vec![0]);
+        assert_eq!(chapter.find_slides_containing("verse"), vec![1, 2]);
+        assert_eq!(chapter.find_slides_containing("gefahren"), vec![2]);
+        assert!(chapter.find_slides_containing("hallelujah").is_empty());
+        assert!(chapter.find_slides_containing("").is_empty());
+    }
+
+    #[test]
+    fn test_retain_slides() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_empty_slide(true),
+                Slide::new_title_slide("Title".to_string(), None),
+                Slide::new_empty_slide(false),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        let expected = vec![chapter.slides[1].uuid(), chapter.slides[3].uuid()];
+
+        chapter.retain_slides(|slide| !slide.is_empty());
+
+        assert_eq!(chapter.len(), 2);
+        assert_eq!(
+            chapter.iter().map(|s| s.uuid()).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    fn numbered_chapter(slide_count: usize) -> PresentationChapter<String, String> {
+        PresentationChapter::new(
+            (1..=slide_count)
+                .map(|i| Slide::new_content_slide(format!("Verse {}", i), None, None))
+                .collect(),
+            LinkedEntity::Title("Reading".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_split_at_start() {
+        let chapter = numbered_chapter(3);
+        let uuids: Vec<Uuid> = chapter.iter().map(|s| s.uuid()).collect();
+
+        let (first, second) = chapter.split_at(0);
+
+        assert!(first.is_empty());
+        assert_eq!(second.iter().map(|s| s.uuid()).collect::<Vec<_>>(), uuids);
+        assert_eq!(first.linked_entity, second.linked_entity);
+    }
+
+    #[test]
+    fn test_split_at_middle() {
+        let chapter = numbered_chapter(4);
+        let uuids: Vec<Uuid> = chapter.iter().map(|s| s.uuid()).collect();
+
+        let (first, second) = chapter.split_at(1);
+
+        assert_eq!(
+            first.iter().map(|s| s.uuid()).collect::<Vec<_>>(),
+            uuids[..1]
+        );
+        assert_eq!(
+            second.iter().map(|s| s.uuid()).collect::<Vec<_>>(),
+            uuids[1..]
+        );
+        assert_eq!(
+            first.linked_entity,
+            LinkedEntity::Title("Reading".to_string())
+        );
+        assert_eq!(
+            second.linked_entity,
+            LinkedEntity::Title("Reading".to_string())
+        );
+    }
+
+    #[test]
+    fn test_split_at_end() {
+        let chapter = numbered_chapter(3);
+
+        let (first, second) = chapter.split_at(3);
+
+        assert_eq!(first.len(), 3);
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_out_of_range() {
+        let _ = numbered_chapter(3).split_at(4);
+    }
+
+    #[test]
+    fn test_flatten_with_separators() {
+        let presentation = Presentation::<String, String>::new(
+            vec![
+                numbered_chapter(2),
+                numbered_chapter(1),
+                numbered_chapter(3),
+            ],
+            None,
+        );
+        let separator = Slide::new_empty_slide(true);
+
+        let slides = presentation.flatten_with_separators(separator.clone());
+
+        assert_eq!(slides.len(), 8);
+        let separator_positions: Vec<usize> = slides
+            .iter()
+            .enumerate()
+            .filter(|(_, slide)| slide.slide_content == separator.slide_content)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(separator_positions, vec![2, 4]);
+        assert_ne!(slides[2].uuid(), slides[4].uuid());
+        assert_eq!(slides[0].uuid(), presentation.chapters[0].slides[0].uuid());
+        assert_eq!(slides[7].uuid(), presentation.chapters[2].slides[2].uuid());
+    }
+
+    #[test]
+    fn test_table_of_contents() {
+        let presentation = Presentation::<String, String>::new(
+            vec![
+                PresentationChapter::new(
+                    vec![
+                        Slide::new_content_slide("Welcome".to_string(), None, None),
+                        Slide::new_title_slide("Amazing Grace".to_string(), None),
+                    ],
+                    LinkedEntity::Title("amazing_grace.song".to_string()),
+                ),
+                PresentationChapter::new(
+                    vec![Slide::new_content_slide("Psalm 23".to_string(), None, None)],
+                    LinkedEntity::Title("Bible Reading".to_string()),
+                ),
+                PresentationChapter::new(
+                    vec![Slide::new_empty_slide(true)],
+                    LinkedEntity::Source("unknown".to_string()),
+                ),
+            ],
+            Some("Sunday Service".to_string()),
+        );
+
+        assert_eq!(
+            presentation.table_of_contents(),
+            vec!["Amazing Grace", "Bible Reading", "Untitled"]
+        );
+    }
+
+    #[test]
+    fn test_notes_are_private() {
+        let slide = Slide::<String>::new_content_slide("Verse 1".to_string(), None, None)
+            .with_notes("Band starts quietly".to_string());
+
+        assert!(slide.has_notes());
+        assert_eq!(slide.to_string(), "Verse 1");
+        assert_eq!(slide.slide_content.all_text(), vec!["Verse 1"]);
+        assert!(!Slide::<String>::new_empty_slide(true).has_notes());
+    }
+
+    #[test]
+    fn test_slide_without_notes_deserializes() {
+        let json = r#"{
+            "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+            "slide_content": {"Empty": {"black_background": true}},
+            "linked_file": null
+        }"#;
+        let slide: Slide<String> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(slide.notes, None);
+        assert!(!slide.has_notes());
+    }
+
+    fn english_german_slide() -> MultiLanguageMainContentSlide {
+        MultiLanguageMainContentSlide {
+            main_text_list: vec![
+                "Amazing grace".to_string(),
+                "Erstaunliche Gnade".to_string(),
+            ],
+            spoiler_text_vector: vec![],
+            meta_text: None,
+            language_codes: vec!["en".to_string(), "de".to_string()],
+            alignment: None,
+            directions: vec![],
+            section_label: None,
+            meta_text_list: vec![],
+            primary_language_index: None,
+        }
+    }
+
+    #[test]
+    fn test_language_line() {
+        let slide = english_german_slide();
+
+        assert_eq!(slide.language_line("en"), Some("Amazing grace"));
+        assert_eq!(slide.language_line("de"), Some("Erstaunliche Gnade"));
+        assert_eq!(slide.language_line("fr"), None);
+        assert!(slide.languages_aligned());
+    }
+
+    #[test]
+    fn test_misaligned_languages() {
+        let mut slide = english_german_slide();
+        slide.language_codes.pop();
+        assert!(!slide.languages_aligned());
+
+        slide.language_codes = vec!["en".to_string(), "de".to_string(), "fr".to_string()];
+        assert!(!slide.languages_aligned());
+        assert_eq!(slide.language_line("fr"), None);
+    }
+
+    #[test]
+    fn test_multi_language_slide_without_codes_deserializes() {
+        let json =
+            r#"{"main_text_list":["Amazing grace"],"spoiler_text_vector":[],"meta_text":null}"#;
+        let slide: MultiLanguageMainContentSlide = serde_json::from_str(json).unwrap();
+
+        assert!(slide.language_codes.is_empty());
+    }
+
+    #[test]
+    fn test_into_multi_language() {
+        let single = SingleLanguageMainContentSlide::new(
+            "Amazing grace".to_string(),
+            Some("How sweet the sound".to_string()),
+            Some("Verse 1".to_string()),
+        );
+
+        let multi = single.into_multi_language("en".to_string());
+
+        assert_eq!(multi.main_text_list, vec!["Amazing grace"]);
+        assert_eq!(multi.spoiler_text_vector, vec!["How sweet the sound"]);
+        assert_eq!(multi.meta_text, Some("Verse 1".to_string()));
+        assert_eq!(multi.language_codes, vec!["en"]);
+        assert!(multi.languages_aligned());
+
+        let without_spoiler =
+            SingleLanguageMainContentSlide::new("Amazing grace".to_string(), None, None)
+                .into_multi_language("en".to_string());
+        assert!(without_spoiler.spoiler_text_vector.is_empty());
+    }
+
+    #[test]
+    fn test_add_language() {
+        let mut slide =
+            SingleLanguageMainContentSlide::new("Amazing grace".to_string(), None, None)
+                .into_multi_language("en".to_string());
+
+        slide.add_language(
+            " de ".to_string(),
+            " Erstaunliche Gnade ".to_string(),
+            Some(" Wie süß der Klang ".to_string()),
+        );
+        slide.add_language("fr".to_string(), "Grâce étonnante".to_string(), None);
+
+        assert_eq!(slide.main_text_list.len(), 3);
+        assert_eq!(slide.spoiler_text_vector.len(), 3);
+        assert_eq!(slide.language_codes.len(), 3);
+        assert!(slide.languages_aligned());
+        assert_eq!(slide.language_line("de"), Some("Erstaunliche Gnade"));
+        assert_eq!(slide.spoiler_text_vector, vec!["", "Wie süß der Klang", ""]);
+    }
+
+    #[test]
+    fn test_empty_multi_language_spoilers_are_not_spoilers() {
+        let mut slide = Slide::<String>::new_multi_language_content_slide(
+            vec!["Amazing grace".to_string()],
+            vec![],
+            None,
+        );
+        if let SlideContent::MultiLanguageMainContent(s) = &mut slide.slide_content {
+            s.add_language("de".to_string(), "Erstaunliche Gnade".to_string(), None);
+        }
+
+        assert!(!slide.has_spoiler());
+        assert_eq!(
+            slide.slide_content.all_text(),
+            vec!["Amazing grace", "Erstaunliche Gnade"]
+        );
+    }
+
+    fn three_language_slide() -> MultiLanguageMainContentSlide {
+        let mut slide = SingleLanguageMainContentSlide::new(
+            "Amazing grace".to_string(),
+            Some("How sweet the sound".to_string()),
+            None,
+        )
+        .into_multi_language("en".to_string());
+        slide.add_language(
+            "de".to_string(),
+            "Erstaunliche Gnade".to_string(),
+            Some("Wie süß der Klang".to_string()),
+        );
+        slide.add_language("fr".to_string(), "Grâce étonnante".to_string(), None);
+        slide
+    }
+
+    #[test]
+    fn test_remove_existing_language() {
+        let mut slide = three_language_slide();
+
+        assert!(slide.remove_language("de"));
+
+        assert_eq!(slide.language_codes, vec!["en", "fr"]);
+        assert_eq!(
+            slide.main_text_list,
+            vec!["Amazing grace", "Grâce étonnante"]
+        );
+        assert_eq!(slide.spoiler_text_vector, vec!["How sweet the sound", ""]);
+    }
+
+    #[test]
+    fn test_remove_missing_language() {
+        let mut slide = three_language_slide();
+
+        assert!(!slide.remove_language("es"));
+
+        assert_eq!(slide, three_language_slide());
+    }
+
+    #[test]
+    fn test_remove_last_language() {
+        let mut slide =
+            SingleLanguageMainContentSlide::new("Amazing grace".to_string(), None, None)
+                .into_multi_language("en".to_string());
+
+        assert!(slide.remove_language("en"));
+
+        assert!(slide.language_codes.is_empty());
+        assert!(slide.main_text_list.is_empty());
+        assert!(slide.spoiler_text_vector.is_empty());
+    }
+
+    #[test]
+    fn test_sort_slides_by_kind() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_title_slide("Title".to_string(), None),
+                Slide::new_multi_language_content_slide(vec!["Verse".to_string()], vec![], None),
+                Slide::new_content_slide("Verse 2".to_string(), None, None),
+                Slide::new_empty_slide(false),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        let uuids: Vec<Uuid> = chapter.iter().map(|s| s.uuid()).collect();
+
+        chapter.sort_slides_by_kind();
+
+        assert_eq!(
+            chapter.iter().map(|s| s.uuid()).collect::<Vec<_>>(),
+            vec![uuids[2], uuids[1], uuids[4], uuids[3], uuids[0], uuids[5]]
+        );
+        assert!(SlideContentKind::Title < SlideContentKind::SingleLanguageMainContent);
+        assert!(SlideContentKind::MultiLanguageMainContent < SlideContentKind::SimplePicture);
+        assert!(SlideContentKind::SimplePicture < SlideContentKind::Empty);
+    }
+
+    #[test]
+    fn test_slides_are_hashable() {
+        let slide = Slide::<String>::new_content_slide("Verse 1".to_string(), None, None);
+        let mut set = std::collections::HashSet::new();
+
+        assert!(set.insert(slide.slide_content.clone()));
+        assert!(!set.insert(
+            Slide::<String>::new_content_slide("Verse 1".to_string(), None, None).slide_content
+        ));
+        assert!(set.insert(
+            Slide::<String>::new_content_slide("Verse 2".to_string(), None, None).slide_content
+        ));
+
+        let mut slides = std::collections::HashSet::new();
+        assert!(slides.insert(slide.clone()));
+        assert!(!slides.insert(slide));
+    }
+
+    #[test]
+    fn test_slide_keys_dedupe_independent_slides() {
+        let first = Slide::<String>::new_content_slide("Verse 1".to_string(), None, None)
+            .with_media("verse.mp3".to_string());
+        let mut second = Slide::<String>::new_content_slide("Verse 1".to_string(), None, None)
+            .with_media("verse.mp3".to_string());
+        second.id = Some("verse-1".to_string());
+        let other_media = Slide::<String>::new_content_slide("Verse 1".to_string(), None, None)
+            .with_media("other.mp3".to_string());
+
+        let slides: std::collections::HashSet<&Slide<String>> = [&first, &second].into();
+        assert_eq!(slides.len(), 2);
+
+        let keys: std::collections::HashSet<SlideKey<String>> =
+            [first.key(), second.key(), other_media.key()].into();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&second.key()));
+    }
+
+    #[test]
+    fn test_dedup_consecutive_slides() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_content_slide("Verse 2".to_string(), None, None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None)
+                    .with_media("grace.mp3".to_string()),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        let expected = vec![
+            chapter.slides[0].uuid(),
+            chapter.slides[3].uuid(),
+            chapter.slides[4].uuid(),
+            chapter.slides[5].uuid(),
+        ];
+
+        chapter.dedup_consecutive_slides();
+
+        assert_eq!(
+            chapter.iter().map(|s| s.uuid()).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_set_and_clear_media_all() {
+        let mut chapter = numbered_chapter(3);
+        chapter.slides[1].linked_file = Some("old.mp3".to_string());
+
+        chapter.set_media_all("backing_track.mp3".to_string());
+        assert!(
+            chapter
+                .iter()
+                .all(|s| s.linked_file == Some("backing_track.mp3".to_string()))
+        );
+
+        chapter.clear_media_all();
+        assert!(chapter.iter().all(|s| s.linked_file.is_none()));
+    }
+
+    #[test]
+    fn test_generate_spoilers() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                Slide::new_content_slide(
+                    "Amazing grace, how sweet the sound\nThat saved a wretch like me".to_string(),
+                    None,
+                    None,
+                ),
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide(
+                    "'Twas grace that taught my heart to fear\nAnd grace my fears relieved"
+                        .to_string(),
+                    Some("Outdated spoiler".to_string()),
+                    None,
+                ),
+                Slide::new_content_slide(
+                    "Through many dangers, toils and snares\nI have already come".to_string(),
+                    Some("Kept spoiler".to_string()),
+                    None,
+                ),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        chapter.generate_spoilers();
+
+        let spoilers: Vec<Option<&str>> = chapter
+            .iter()
+            .filter_map(|slide| match &slide.slide_content {
+                SlideContent::SingleLanguageMainContent(s) => Some(s.spoiler_text.as_deref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            spoilers,
+            vec![
+                Some("'Twas grace that taught my heart to fear"),
+                Some("Through many dangers, toils and snares"),
+                Some("Kept spoiler"),
+            ]
+        );
+        assert!(!chapter.slides[0].has_spoiler());
+    }
+
+    #[test]
+    fn test_clear_spoilers() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Amazing Grace".to_string(),
+                    Some("John Newton".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Amazing grace".to_string(),
+                    Some("How sweet the sound".to_string()),
+                    Some("Verse 1".to_string()),
+                ),
+                Slide::new_multi_language_content_slide(
+                    vec![
+                        "Amazing grace".to_string(),
+                        "Erstaunliche Gnade".to_string(),
+                    ],
+                    vec![
+                        "How sweet the sound".to_string(),
+                        "Wie süß der Klang".to_string(),
+                    ],
+                    None,
+                ),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        chapter.clear_spoilers();
+
+        assert!(chapter.iter().all(|slide| !slide.has_spoiler()));
+        assert!(chapter.slides[1].has_meta_text());
+        assert_eq!(chapter.slides[1].to_string(), "Amazing grace");
+        assert_eq!(
+            chapter.slides[2].to_string(),
+            "Amazing grace / Erstaunliche Gnade"
+        );
+    }
+
+    #[test]
+    fn test_group_consecutive_by_kind() {
+        let slides = vec![
+            Slide::<String>::new_title_slide("Title".to_string(), None),
+            Slide::new_content_slide("Verse 1".to_string(), None, None),
+            Slide::new_content_slide("Verse 2".to_string(), None, None),
+            Slide::new_empty_slide(true),
+            Slide::new_content_slide("Verse 3".to_string(), None, None),
+        ];
+
+        let groups = group_consecutive(&slides, |slide| slide.kind());
+
+        let summary: Vec<(SlideContentKind, usize)> = groups
+            .iter()
+            .map(|(kind, group)| (*kind, group.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (SlideContentKind::Title, 1),
+                (SlideContentKind::SingleLanguageMainContent, 2),
+                (SlideContentKind::Empty, 1),
+                (SlideContentKind::SingleLanguageMainContent, 1),
+            ]
+        );
+        assert_eq!(groups[1].1[1].uuid(), slides[2].uuid());
+        assert!(group_consecutive(&[] as &[Slide<String>], |slide| slide.kind()).is_empty());
+    }
+
+    #[test]
+    fn test_slide_kind_predicates() {
+        let empty = Slide::<String>::new_empty_slide(true);
+        assert!(empty.is_empty());
+        assert!(!empty.is_title() && !empty.is_content() && !empty.is_picture());
+
+        let title = Slide::<String>::new_title_slide("Title".to_string(), None);
+        assert!(title.is_title());
+        assert!(!title.is_empty() && !title.is_content() && !title.is_picture());
+
+        let content = Slide::<String>::new_content_slide("Verse".to_string(), None, None);
+        assert!(content.is_content());
+        assert!(!content.is_empty() && !content.is_title() && !content.is_picture());
+
+        let multi_language = Slide::<String>::new_multi_language_content_slide(
+            vec!["Verse".to_string()],
+            vec![],
+            None,
+        );
+        assert!(multi_language.is_content());
+
+        let picture = SlideBuilder::<String>::new()
+            .picture("cross.png".to_string())
+            .build();
+        assert!(picture.is_picture());
+        assert!(!picture.is_empty() && !picture.is_title() && !picture.is_content());
+    }
+
+    #[test]
+    fn test_chapter_len_and_is_empty() {
+        let empty = PresentationChapter::<String, String>::new(
+            vec![],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+
+        let chapter = numbered_chapter(3);
+        assert_eq!(chapter.len(), 3);
+        assert!(!chapter.is_empty());
+    }
+
+    #[test]
+    fn test_chapter_indexing() {
+        let mut chapter = numbered_chapter(3);
+
+        assert_eq!(chapter[1].to_string(), "Verse 2");
+        chapter[2].notes = Some("Last verse".to_string());
+        assert!(chapter.slides[2].has_notes());
+
+        assert_eq!(chapter.get(0).map(|s| s.uuid()), Some(chapter[0].uuid()));
+        assert!(chapter.get(3).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chapter_indexing_out_of_bounds() {
+        let chapter = numbered_chapter(3);
+        let _ = &chapter[3];
+    }
+
+    #[test]
+    fn test_chapter_from_slides() {
+        let chapter = PresentationChapter::<String, String>::from_slides(
+            (1..=3).map(|i| Slide::new_content_slide(format!("Verse {}", i), None, None)),
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        assert_eq!(chapter.len(), 3);
+        assert_eq!(chapter[2].to_string(), "Verse 3");
+    }
+
+    #[test]
+    fn test_extend_chapter() {
+        let mut chapter = numbered_chapter(2);
+
+        chapter
+            .extend((3..=4).map(|i| Slide::new_content_slide(format!("Verse {}", i), None, None)));
+
+        assert_eq!(chapter.len(), 4);
+        assert_eq!(
+            chapter.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+            vec!["Verse 1", "Verse 2", "Verse 3", "Verse 4"]
+        );
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Reading".to_string())
+        );
+    }
+
+    fn slide_texts(chapter: &PresentationChapter<String, String>) -> Vec<String> {
+        chapter.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_insert_slide() {
+        let mut chapter = numbered_chapter(2);
+
+        chapter.insert_slide(0, Slide::new_title_slide("Title".to_string(), None));
+        chapter.insert_slide(2, Slide::new_empty_slide(true));
+        chapter.insert_slide(4, Slide::new_content_slide("Amen".to_string(), None, None));
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec!["Title", "Verse 1", "[empty]", "Verse 2", "Amen"]
+        );
+    }
+
+    #[test]
+    fn test_remove_slide() {
+        let mut chapter = numbered_chapter(3);
+
+        let removed = chapter.remove_slide(1);
+
+        assert_eq!(removed.to_string(), "Verse 2");
+        assert_eq!(slide_texts(&chapter), vec!["Verse 1", "Verse 3"]);
+    }
+
+    #[test]
+    fn test_try_remove_slide() {
+        let mut chapter = numbered_chapter(2);
+
+        assert!(chapter.try_remove_slide(2).is_none());
+        assert_eq!(chapter.len(), 2);
+        assert_eq!(
+            chapter.try_remove_slide(1).map(|s| s.to_string()),
+            Some("Verse 2".to_string())
+        );
+        assert_eq!(slide_texts(&chapter), vec!["Verse 1"]);
+    }
+
+    #[test]
+    fn test_reverse_slides() {
+        let mut chapter = numbered_chapter(3);
+
+        chapter.reverse_slides();
+        assert_eq!(slide_texts(&chapter), vec!["Verse 3", "Verse 2", "Verse 1"]);
+
+        let reversed = chapter.reversed();
+        assert_eq!(
+            slide_texts(&reversed),
+            vec!["Verse 1", "Verse 2", "Verse 3"]
+        );
+        assert_eq!(
+            reversed.linked_entity,
+            LinkedEntity::Title("Reading".to_string())
+        );
+    }
+
+    #[test]
+    fn test_content_eq_ignores_media() {
+        let canonical = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None)
+                    .with_media("/canonical/grace.mp3".to_string()),
+            ],
+            LinkedEntity::Media("/canonical/grace.mp3".to_string()),
+        );
+        let mut imported = canonical.clone();
+        imported.set_media_all("/imported/grace.mp3".to_string());
+        imported.linked_entity = LinkedEntity::Media("/imported/grace.mp3".to_string());
+
+        assert!(imported.content_eq(&canonical));
+        assert_ne!(imported, canonical);
+        assert!(imported[1].content_eq(&canonical[1]));
+        assert_ne!(imported[1], canonical[1]);
+    }
+
+    #[test]
+    fn test_content_eq_detects_content_changes() {
+        let chapter = numbered_chapter(2);
+
+        let mut changed = chapter.clone();
+        changed[1] = Slide::new_content_slide("Verse 2 (corrected)".to_string(), None, None);
+        assert!(!changed.content_eq(&chapter));
+
+        let mut retitled = chapter.clone();
+        retitled.linked_entity = LinkedEntity::Title("Another Reading".to_string());
+        assert!(retitled.content_eq(&chapter));
+
+        let mut relinked = chapter.clone();
+        relinked.linked_entity = LinkedEntity::Media("reading.mp3".to_string());
+        assert!(!relinked.content_eq(&chapter));
+
+        let mut shortened = chapter.clone();
+        shortened.remove_slide(1);
+        assert!(!shortened.content_eq(&chapter));
+
+        // Independently created slides with the same content differ only by their UUIDs
+        assert!(numbered_chapter(2).content_eq(&chapter));
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        let picture = Slide::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new("  cross.png ".to_string())
+                .with_alt_text("  A   cross ".to_string()),
+        ));
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None)
+                    .with_notes(" Wait  for the\tpiano ".to_string()),
+                Slide::new_content_slide("Verse".to_string(), None, None),
+                picture,
+            ],
+            LinkedEntity::Title("  Amazing   Grace ".to_string()),
+        );
+        chapter[0].slide_content = SlideContent::Title(TitleSlide {
+            title_text: " Amazing \t Grace ".to_string(),
+            meta_text: Some("John  Newton\t".to_string()),
+        });
+        if let SlideContent::SingleLanguageMainContent(s) = &mut chapter[1].slide_content {
+            s.main_text = "  Amazing grace,\thow  sweet the sound   \n\n\n\n  That saved a wretch like me \n\n".to_string();
+            s.section_label = Some(" Verse  1 ".to_string());
+        }
+
+        chapter.normalize_text();
+
+        assert_eq!(
+            chapter[0].slide_content.all_text(),
+            vec!["Amazing Grace", "John Newton"]
+        );
+        assert_eq!(
+            chapter[1].to_string(),
+            "Amazing grace, how sweet the sound\n\nThat saved a wretch like me"
+        );
+        let SlideContent::SingleLanguageMainContent(verse) = &chapter[1].slide_content else {
+            panic!("The verse is a content slide");
+        };
+        assert_eq!(verse.section_label.as_deref(), Some("Verse 1"));
+        assert_eq!(
+            chapter[2].slide_content,
+            SlideContent::SimplePicture(
+                SimplePictureSlide::new("  cross.png ".to_string())
+                    .with_alt_text("A cross".to_string())
+            )
+        );
+        assert_eq!(chapter[0].notes.as_deref(), Some("Wait for the piano"));
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Amazing Grace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_char_and_word_count() {
+        let content = Slide::<String>::new_content_slide(
+            "Amazing grace, how sweet\nthe sound".to_string(),
+            None,
+            Some("Verse 1".to_string()),
+        );
+        assert_eq!(content.slide_content.word_count(), 8);
+        assert_eq!(content.slide_content.char_count(), 34 + 7);
+
+        let multi_language = Slide::<String>::new_multi_language_content_slide(
+            vec![
+                "Amazing grace".to_string(),
+                "Erstaunliche\u{3000}Gnade".to_string(),
+            ],
+            vec!["Wie süß".to_string()],
+            None,
+        );
+        assert_eq!(multi_language.slide_content.word_count(), 6);
+        assert_eq!(multi_language.slide_content.char_count(), 13 + 18 + 7);
+
+        assert_eq!(
+            Slide::<String>::new_empty_slide(true)
+                .slide_content
+                .word_count(),
+            0
+        );
+
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![content, multi_language],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+        assert_eq!(chapter.word_count(), 14);
+    }
+
+    #[test]
+    fn test_estimated_reading_time() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![Slide::new_content_slide(
+                vec!["word"; 300].join(" "),
+                None,
+                None,
+            )],
+            LinkedEntity::Title("Announcements".to_string()),
+        );
+
+        assert_eq!(chapter.word_count(), 300);
+        assert_eq!(chapter.estimated_reading_time(200), Duration::from_secs(90));
+        assert_eq!(chapter.estimated_reading_time(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_partition_slides() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Title".to_string(), None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None),
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide("Verse 2".to_string(), None, None),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        let (content, other) = chapter.partition_slides(|slide| slide.is_content());
+
+        assert_eq!(
+            content.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+            vec!["Verse 1", "Verse 2"]
+        );
+        assert_eq!(
+            other.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+            vec!["Title", "[empty]"]
+        );
+    }
+
+    #[test]
+    fn test_json_helpers_roundtrip() {
+        let chapter = PresentationChapter::<Song, SongFile>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                Slide::new_content_slide("Verse 1".to_string(), None, None).with_media(SongFile {
+                    path: "/assets/grace.mp3".to_string(),
+                }),
+            ],
+            LinkedEntity::Source(Song {
+                id: 1,
+                title: "Amazing Grace".to_string(),
+            }),
+        );
+
+        let pretty = chapter.to_json_pretty().unwrap();
+        let compact = chapter.to_json_compact().unwrap();
+
+        assert!(pretty.contains('\n'));
+        assert!(!compact.contains('\n'));
+        assert!(compact.len() < pretty.len());
+        assert_eq!(
+            PresentationChapter::from_json_str(&pretty).unwrap(),
+            chapter
+        );
+        assert_eq!(
+            PresentationChapter::from_json_str(&compact).unwrap(),
+            chapter
+        );
+    }
+
+    #[test]
+    fn test_from_json_str_error() {
+        assert!(PresentationChapter::<String, String>::from_json_str("{\"slides\": 1}").is_err());
+    }
+
+    #[test]
+    fn test_none_fields_are_not_serialized() {
+        let slide = Slide::<String>::new_content_slide("Verse 1".to_string(), None, None);
+
+        let json = serde_json::to_string(&slide).unwrap();
+
+        assert!(!json.contains("null"));
+        assert!(!json.contains("spoiler_text"));
+        assert!(!json.contains("linked_file"));
+        assert_eq!(serde_json::from_str::<Slide<String>>(&json).unwrap(), slide);
+    }
+
+    #[test]
+    fn test_null_fields_still_deserialize() {
+        let json = r#"{
+            "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+            "slide_content": {"SingleLanguageMainContent": {
+                "main_text": "Verse 1",
+                "spoiler_text": null,
+                "meta_text": null
+            }},
+            "linked_file": null,
+            "transition": null,
+            "duration_millis": null,
+            "notes": null
+        }"#;
+
+        let slide: Slide<String> = serde_json::from_str(json).unwrap();
+
+        assert!(!slide.has_spoiler());
+        assert!(!slide.has_meta_text());
+        assert_eq!(slide.linked_file, None);
+    }
+
+    #[test]
+    fn test_strict_json_rejects_unknown_fields() {
+        let json = r#"{
+            "slides": [{
+                "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+                "slide_content": {"SingleLanguageMainContent": {
+                    "main_text": "Verse 1",
+                    "mian_text": "Verse 1"
+                }}
+            }],
+            "linked_entity": {"Title": "Amazing Grace"}
+        }"#;
+
+        let lenient = PresentationChapter::<String, String>::from_json_str(json).unwrap();
+        assert_eq!(lenient[0].to_string(), "Verse 1");
+
+        let error = PresentationChapter::<String, String>::from_json_str_strict(json).unwrap_err();
+        assert!(error.to_string().contains("mian_text"));
+    }
+
+    #[test]
+    fn test_strict_json_accepts_known_fields() {
+        let chapter = numbered_chapter(2);
+        let json = chapter.to_json_compact().unwrap();
+
+        assert_eq!(
+            PresentationChapter::from_json_str_strict(&json).unwrap(),
+            chapter
+        );
+    }
+
+    #[test]
+    fn test_default_slide_content() {
+        assert!(EmptySlide::default().black_background);
+        assert_eq!(
+            SlideContent::default(),
+            SlideContent::Empty(EmptySlide {
+                black_background: true
+            })
+        );
+
+        let single = SingleLanguageMainContentSlide::default();
+        assert!(single.main_text.is_empty());
+        assert_eq!(single.spoiler_text, None);
+        assert_eq!(single.meta_text, None);
+
+        let multi = MultiLanguageMainContentSlide::default();
+        assert!(multi.main_text_list.is_empty());
+        assert!(multi.spoiler_text_vector.is_empty());
+        assert!(multi.language_codes.is_empty());
+        assert_eq!(multi.meta_text, None);
+
+        let title = TitleSlide::default();
+        assert!(title.title_text.is_empty());
+        assert_eq!(title.meta_text, None);
+
+        assert_eq!(
+            SimplePictureSlide::default(),
+            SimplePictureSlide::new(String::new())
+        );
+    }
+
+    #[test]
+    fn test_background_serialization() {
+        let slide = Slide::<String>::new_title_slide("Amazing Grace".to_string(), None)
+            .with_background(Background::Color {
+                rgba: [20, 40, 80, 255],
+            });
+        let picture_slide =
+            Slide::<String>::new_empty_slide(false).with_background(Background::Image {
+                path: "sunrise.jpg".to_string(),
+            });
+
+        for slide in [slide, picture_slide] {
+            let json = serde_json::to_string(&slide).unwrap();
+            let deserialized: Slide<String> = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, slide);
+        }
+
+        let json = r#"{
+            "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+            "slide_content": {"Empty": {"black_background": true}}
+        }"#;
+        let slide: Slide<String> = serde_json::from_str(json).unwrap();
+        assert_eq!(slide.background, None);
+        assert!(
+            serde_json::to_value(&slide)
+                .unwrap()
+                .get("background")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_text_alignment_serialization() {
+        let single = SingleLanguageMainContentSlide::new("Amazing grace".to_string(), None, None)
+            .with_alignment(TextAlignment::Left);
+        let multi = english_german_slide().with_alignment(TextAlignment::Justify);
+
+        let json = serde_json::to_string(&single).unwrap();
+        assert!(json.contains(r#""alignment":"Left""#));
+        assert_eq!(
+            serde_json::from_str::<SingleLanguageMainContentSlide>(&json).unwrap(),
+            single
+        );
+        let json = serde_json::to_string(&multi).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MultiLanguageMainContentSlide>(&json).unwrap(),
+            multi
+        );
+
+        let slide: SingleLanguageMainContentSlide =
+            serde_json::from_str(r#"{"main_text": "Amazing grace"}"#).unwrap();
+        assert_eq!(slide.alignment, None);
+        assert_eq!(
+            single.into_multi_language("en".to_string()).alignment,
+            Some(TextAlignment::Left)
+        );
+    }
+
+    #[test]
+    fn test_single_language_text_direction() {
+        let mut slide = SingleLanguageMainContentSlide::new("חסד מופלא".to_string(), None, None);
+        assert!(!slide.is_rtl());
+
+        slide.direction = Some(TextDirection::Rtl);
+        assert!(slide.is_rtl());
+        let json = serde_json::to_string(&slide).unwrap();
+        assert!(json.contains(r#""direction":"Rtl""#));
+        assert_eq!(
+            serde_json::from_str::<SingleLanguageMainContentSlide>(&json).unwrap(),
+            slide
+        );
+
+        slide.direction = Some(TextDirection::Ltr);
+        assert!(!slide.is_rtl());
+    }
+
+    #[test]
+    fn test_multi_language_text_directions() {
+        let mut slide =
+            SingleLanguageMainContentSlide::new("Amazing grace".to_string(), None, None);
+        slide.direction = Some(TextDirection::Ltr);
+        let mut slide = slide.into_multi_language("en".to_string());
+        slide.add_language_with_direction(
+            "he".to_string(),
+            "חסד מופלא".to_string(),
+            None,
+            Some(TextDirection::Rtl),
+        );
+        slide.add_language("en-GB".to_string(), "Amazing grace".to_string(), None);
+
+        assert_eq!(
+            slide.directions,
+            vec![TextDirection::Ltr, TextDirection::Rtl, TextDirection::Ltr]
+        );
+        assert!(!slide.is_rtl_at(0));
+        assert!(slide.is_rtl_at(1));
+        assert!(!slide.is_rtl_at(2));
+
+        let json = serde_json::to_string(&slide).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MultiLanguageMainContentSlide>(&json).unwrap(),
+            slide
+        );
+
+        assert!(slide.remove_language("en"));
+        assert_eq!(
+            slide.directions,
+            vec![TextDirection::Rtl, TextDirection::Ltr]
+        );
+        assert!(slide.is_rtl_at(0));
+
+        let legacy: MultiLanguageMainContentSlide =
+            serde_json::from_str(r#"{"main_text_list": ["a"], "spoiler_text_vector": []}"#)
+                .unwrap();
+        assert!(legacy.directions.is_empty());
+        assert!(!legacy.is_rtl_at(0));
+    }
+
+    #[test]
+    fn test_slide_id_roundtrip() {
+        let mut slide = Slide::<String>::new_title_slide("Amazing Grace".to_string(), None);
+        assert_eq!(slide.id, None);
+        assert!(!serde_json::to_string(&slide).unwrap().contains("\"id\""));
+
+        slide.id = Some("amazing-grace-title".to_string());
+        let json = serde_json::to_string(&slide).unwrap();
+        let deserialized: Slide<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.id, slide.id);
+        assert_eq!(slide.duplicate().id, None);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_ensure_id() {
+        let mut slide = Slide::<String>::new_title_slide("Amazing Grace".to_string(), None);
+
+        slide.ensure_id();
+        let id = slide.id.clone().unwrap();
+        assert!(Uuid::parse_str(&id).is_ok());
+        slide.ensure_id();
+        assert_eq!(slide.id, Some(id));
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_ensure_all_ids() {
+        let mut chapter = numbered_chapter(3);
+        chapter.slides[1].id = Some("verse-2".to_string());
+
+        chapter.ensure_all_ids();
+
+        assert!(chapter.iter().all(|slide| slide.id.is_some()));
+        assert_eq!(chapter.slides[1].id, Some("verse-2".to_string()));
+        assert_ne!(chapter.slides[0].id, chapter.slides[2].id);
+    }
+
+    fn chapter_with_ids(ids: &[&str]) -> PresentationChapter<String, String> {
+        let mut chapter = numbered_chapter(ids.len());
+        for (slide, id) in chapter.slides.iter_mut().zip(ids) {
+            slide.id = Some(id.to_string());
+        }
+        chapter
+    }
+
+    #[test]
+    fn test_slide_by_id() {
+        let mut chapter = chapter_with_ids(&["one", "two", "three"]);
+
+        assert_eq!(
+            chapter.slide_by_id("two").unwrap().uuid(),
+            chapter[1].uuid()
+        );
+        assert!(chapter.slide_by_id("four").is_none());
+
+        chapter.slide_by_id_mut("three").unwrap().notes = Some("Last verse".to_string());
+        assert_eq!(chapter[2].notes, Some("Last verse".to_string()));
+        assert!(chapter.slide_by_id_mut("four").is_none());
+        assert!(chapter.duplicate_ids().is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_ids() {
+        let chapter = chapter_with_ids(&["b", "a", "b", "c", "a", "b"]);
+
+        assert_eq!(chapter.slide_by_id("b").unwrap().uuid(), chapter[0].uuid());
+        assert_eq!(
+            chapter.duplicate_ids(),
+            vec!["b".to_string(), "a".to_string()]
+        );
+    }
+
+    struct LibraryResolver {
+        library: HashMap<String, PathBuf>,
+    }
+
+    impl MediaResolver<String> for LibraryResolver {
+        fn resolve(&self, media: &String) -> Option<PathBuf> {
+            self.library.get(media).cloned()
+        }
+    }
+
+    #[test]
+    fn test_resolve_media() {
+        let mut chapter = numbered_chapter(4);
+        chapter.slides[0].linked_file = Some("amazing-grace".to_string());
+        chapter.slides[2].linked_file = Some("unknown".to_string());
+        chapter.slides[3].linked_file = Some("amazing-grace".to_string());
+        let resolver = LibraryResolver {
+            library: HashMap::from([(
+                "amazing-grace".to_string(),
+                PathBuf::from("/songs/amazing-grace.mp3"),
+            )]),
+        };
+
+        assert_eq!(
+            chapter.resolve_media(&resolver),
+            vec![
+                (0, PathBuf::from("/songs/amazing-grace.mp3")),
+                (3, PathBuf::from("/songs/amazing-grace.mp3")),
+            ]
+        );
+
+        let resolved =
+            chapter.resolve_media(&|media: &String| Some(PathBuf::from("/media").join(media)));
+        assert_eq!(resolved.len(), 3);
+        assert_eq!(resolved[1], (2, PathBuf::from("/media/unknown")));
+    }
+
+    #[test]
+    fn test_validate_picture_paths() {
+        let existing = std::env::temp_dir().join(format!("cantara-slides-{}.png", Uuid::now_v7()));
+        std::fs::write(&existing, b"").unwrap();
+        let picture = |path: &str| {
+            Slide::<String>::from_content(SlideContent::SimplePicture(SimplePictureSlide::new(
+                path.to_string(),
+            )))
+        };
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                picture(existing.to_str().unwrap()),
+                Slide::new_video_slide("/does/not/exist.mp4".to_string(), false, false),
+                picture("/does/not/exist.png"),
+            ],
+            LinkedEntity::Title("Pictures".to_string()),
+        );
+
+        let missing = chapter.validate_picture_paths();
+        std::fs::remove_file(&existing).unwrap();
+
+        assert_eq!(missing, vec![(2, "/does/not/exist.png".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_section_kind() {
+        assert_eq!(SectionKind::parse("Verse 2"), SectionKind::Verse(2));
+        assert_eq!(SectionKind::parse("  verse  12 "), SectionKind::Verse(12));
+        assert_eq!(SectionKind::parse("Strophe 3"), SectionKind::Verse(3));
+        assert_eq!(SectionKind::parse("Verse"), SectionKind::Verse(1));
+        assert_eq!(SectionKind::parse("Chorus"), SectionKind::Chorus);
+        assert_eq!(SectionKind::parse("REFRAIN"), SectionKind::Chorus);
+        assert_eq!(SectionKind::parse("Bridge"), SectionKind::Bridge);
+        assert_eq!(
+            SectionKind::parse("Pre-Chorus"),
+            SectionKind::Other("Pre-Chorus".to_string())
+        );
+        assert_eq!(
+            SectionKind::parse("Verse two"),
+            SectionKind::Other("Verse two".to_string())
+        );
+        assert_eq!(
+            SectionKind::parse("Chorus 2 (key change)"),
+            SectionKind::Other("Chorus 2 (key change)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_with_section() {
+        let slide = SingleLanguageMainContentSlide::new("Amazing grace".to_string(), None, None)
+            .with_section(SectionKind::Verse(1));
+        assert_eq!(slide.section_label, Some("Verse 1".to_string()));
+        assert_eq!(slide.section_kind(), Some(SectionKind::Verse(1)));
+
+        let multi = slide.into_multi_language("en".to_string());
+        assert_eq!(multi.section_kind(), Some(SectionKind::Verse(1)));
+        let multi = multi.with_section(SectionKind::Other("Outro".to_string()));
+        assert_eq!(multi.section_label, Some("Outro".to_string()));
+
+        let json = serde_json::to_string(&multi).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MultiLanguageMainContentSlide>(&json).unwrap(),
+            multi
+        );
+        assert_eq!(english_german_slide().section_kind(), None);
+    }
+
+    fn chord(char_offset: usize, chord: &str) -> ChordAnnotation {
+        ChordAnnotation {
+            char_offset,
+            chord: chord.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_chords_at_line() {
+        // "Amazing grace" has 13 characters, so the second line starts at 14
+        let slide = SingleLanguageMainContentSlide {
+            main_text: "Amazing grace\nhow sweet the sound\n\nthat saved a wretch".to_string(),
+            chords: Some(vec![
+                chord(0, "G"),
+                chord(8, "G7"),
+                chord(14, "C"),
+                chord(33, "G"),
+                chord(35, "D"),
+            ]),
+            ..Default::default()
+        };
+
+        let chords_at = |line| {
+            slide
+                .chords_at_line(line)
+                .into_iter()
+                .map(|c| c.chord.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(chords_at(0), vec!["G", "G7"]);
+        assert_eq!(chords_at(1), vec!["C", "G"]);
+        assert!(chords_at(2).is_empty());
+        assert_eq!(chords_at(3), vec!["D"]);
+        assert!(chords_at(4).is_empty());
+        assert!(verse_with_lines(2).chords_at_line(0).is_empty());
+    }
+
+    #[test]
+    fn test_split_by_lines_moves_chords() {
+        let mut slide = verse_with_lines(4);
+        // Every line ("Line i") has 6 characters
+        slide.chords = Some(vec![chord(0, "G"), chord(7, "C"), chord(15, "D")]);
+
+        let parts = slide.split_by_lines(2);
+
+        assert_eq!(parts[0].chords, Some(vec![chord(0, "G"), chord(7, "C")]));
+        assert_eq!(parts[1].chords, Some(vec![chord(1, "D")]));
+        assert_eq!(parts[1].chords_at_line(0)[0].chord, "D");
+    }
+
+    fn section_slide(main_text: &str, section: SectionKind) -> Slide<String> {
+        Slide::from_content(SlideContent::SingleLanguageMainContent(
+            SingleLanguageMainContentSlide::new(main_text.to_string(), None, None)
+                .with_section(section),
+        ))
+    }
+
+    #[test]
+    fn test_expand_choruses() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                section_slide("Verse one", SectionKind::Verse(1)),
+                section_slide("Chorus", SectionKind::Chorus),
+                Slide::new_content_slide("Verse two".to_string(), None, None),
+                section_slide("Bridge", SectionKind::Bridge),
+                section_slide("Verse three", SectionKind::Verse(3)),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+        let chorus_uuid = chapter[2].uuid();
+
+        chapter.expand_choruses(&[2, 42]);
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec![
+                "Amazing Grace",
+                "Verse one",
+                "Chorus",
+                "Verse two",
+                "Chorus",
+                "Bridge",
+                "Verse three",
+                "Chorus",
+            ]
+        );
+        let repeats: Vec<usize> = (0..chapter.len())
+            .filter(|&index| chapter[index].is_repeat)
+            .collect();
+        assert_eq!(repeats, vec![4, 7]);
+        assert_eq!(chapter[2].uuid(), chorus_uuid);
+        assert_ne!(chapter[4].uuid(), chorus_uuid);
+        assert_ne!(chapter[4].uuid(), chapter[7].uuid());
+
+        let json = serde_json::to_value(&chapter).unwrap();
+        assert_eq!(json["slides"][4]["is_repeat"], serde_json::json!(true));
+        assert!(json["slides"][2].get("is_repeat").is_none());
+    }
+
+    #[test]
+    fn test_meta_text_for() {
+        let mut slide = english_german_slide();
+        assert_eq!(slide.meta_text_for("en"), None);
+
+        slide.meta_text = Some("Public domain".to_string());
+        assert_eq!(slide.meta_text_for("en"), Some("Public domain"));
+        assert_eq!(slide.meta_text_for("fr"), Some("Public domain"));
+
+        slide.meta_text_list = vec![String::new(), "Übersetzung: CCLI 1234".to_string()];
+        assert_eq!(slide.meta_text_for("en"), Some("Public domain"));
+        assert_eq!(slide.meta_text_for("de"), Some("Übersetzung: CCLI 1234"));
+
+        assert!(slide.remove_language("en"));
+        assert_eq!(slide.meta_text_for("de"), Some("Übersetzung: CCLI 1234"));
+    }
+
+    #[test]
+    fn test_to_single_language() {
+        let mut slide = three_language_slide();
+        slide.meta_text = Some("John Newton".to_string());
+        slide.directions = vec![TextDirection::Ltr; 3];
+
+        let german = slide.to_single_language("de").unwrap();
+        assert_eq!(german.main_text, "Erstaunliche Gnade");
+        assert_eq!(german.spoiler_text, Some("Wie süß der Klang".to_string()));
+        assert_eq!(german.meta_text, Some("John Newton".to_string()));
+        assert_eq!(german.direction, Some(TextDirection::Ltr));
+
+        assert_eq!(slide.to_single_language("fr").unwrap().spoiler_text, None);
+        assert_eq!(slide.to_single_language("es"), None);
+
+        // Without aligned spoilers only the main text can be taken over
+        let english = MultiLanguageMainContentSlide {
+            spoiler_text_vector: vec!["Wie süß der Klang".to_string()],
+            ..english_german_slide()
+        };
+        assert_eq!(english.to_single_language("de").unwrap().spoiler_text, None);
+    }
+
+    #[test]
+    fn test_collapse_to_language() {
+        let multi = Slide::<String>::from_content(SlideContent::MultiLanguageMainContent(
+            three_language_slide(),
+        ));
+        let english_only = Slide::<String>::from_content(SlideContent::MultiLanguageMainContent(
+            SingleLanguageMainContentSlide::new("How sweet the sound".to_string(), None, None)
+                .into_multi_language("en".to_string()),
+        ));
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                multi,
+                english_only,
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        chapter.collapse_to_language("de");
+
+        assert!(chapter[0].is_title());
+        assert_eq!(
+            chapter[1].kind(),
+            SlideContentKind::SingleLanguageMainContent
+        );
+        assert_eq!(chapter[1].to_string(), "Erstaunliche Gnade");
+        assert_eq!(
+            chapter[2].kind(),
+            SlideContentKind::MultiLanguageMainContent
+        );
+    }
+
+    fn codes(codes: &[&str]) -> Vec<String> {
+        codes.iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn test_reorder_languages() {
+        let mut slide = three_language_slide();
+        slide.meta_text_list = codes(&["John Newton"]);
+
+        slide
+            .reorder_languages(&codes(&["de", "fr", "en"]))
+            .unwrap();
+
+        assert_eq!(slide.language_codes, codes(&["de", "fr", "en"]));
+        assert_eq!(
+            slide.main_text_list,
+            codes(&["Erstaunliche Gnade", "Grâce étonnante", "Amazing grace"])
+        );
+        assert_eq!(
+            slide.spoiler_text_vector,
+            codes(&["Wie süß der Klang", "", "How sweet the sound"])
+        );
+        assert_eq!(slide.meta_text_list, codes(&["", "", "John Newton"]));
+        assert_eq!(slide.language_line("en"), Some("Amazing grace"));
+    }
+
+    #[test]
+    fn test_reorder_languages_with_partial_directions() {
+        let mut slide = SingleLanguageMainContentSlide::new("חסד מופלא".to_string(), None, None)
+            .into_multi_language("he".to_string());
+        slide.directions = vec![TextDirection::Rtl];
+        slide.main_text_list.push("Amazing grace".to_string());
+        slide.language_codes.push("en".to_string());
+
+        slide.reorder_languages(&codes(&["en", "he"])).unwrap();
+
+        assert_eq!(
+            slide.directions,
+            vec![TextDirection::Ltr, TextDirection::Rtl]
+        );
+        assert!(!slide.is_rtl_at(0));
+        assert!(slide.is_rtl_at(1));
+    }
+
+    #[test]
+    fn test_reorder_languages_mismatch() {
+        let mut slide = three_language_slide();
+        let original = slide.clone();
+
+        for order in [
+            codes(&["de", "en"]),
+            codes(&["de", "en", "es"]),
+            codes(&["de", "en", "fr", "es"]),
+            codes(&["de", "de", "en"]),
+        ] {
+            assert_eq!(
+                slide.reorder_languages(&order),
+                Err(LanguageError::OrderMismatch)
+            );
+        }
+        assert_eq!(slide, original);
+
+        slide.language_codes.pop();
+        assert_eq!(
+            slide.reorder_languages(&codes(&["de", "en"])),
+            Err(LanguageError::Misaligned)
+        );
+    }
+
+    #[test]
+    fn test_primary_main_text() {
+        let mut slide = three_language_slide();
+        assert_eq!(slide.primary_main_text(), Some("Amazing grace"));
+
+        slide.primary_language_index = Some(1);
+        assert_eq!(slide.primary_main_text(), Some("Erstaunliche Gnade"));
+
+        slide.primary_language_index = Some(3);
+        assert_eq!(slide.primary_main_text(), Some("Amazing grace"));
+
+        assert_eq!(
+            MultiLanguageMainContentSlide::default().primary_main_text(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_primary_language_follows_changes() {
+        let mut slide = three_language_slide();
+        slide.primary_language_index = Some(1);
+
+        slide
+            .reorder_languages(&codes(&["fr", "en", "de"]))
+            .unwrap();
+        assert_eq!(slide.primary_language_index, Some(2));
+        assert_eq!(slide.primary_main_text(), Some("Erstaunliche Gnade"));
+
+        assert!(slide.remove_language("fr"));
+        assert_eq!(slide.primary_main_text(), Some("Erstaunliche Gnade"));
+        assert!(slide.remove_language("de"));
+        assert_eq!(slide.primary_language_index, None);
+    }
+
+    #[test]
+    fn test_append_slides_from() {
+        let mut chapter = numbered_chapter(2);
+        let refrain = PresentationChapter::<String, String>::new(
+            vec![Slide::new_content_slide("Refrain".to_string(), None, None)],
+            LinkedEntity::Title("Refrain".to_string()),
+        );
+
+        chapter.append_slides_from(&refrain);
+        chapter.append_slides_from(&refrain);
+
+        assert_eq!(chapter.len(), 4);
+        assert_eq!(
+            slide_texts(&chapter),
+            vec!["Verse 1", "Verse 2", "Refrain", "Refrain"]
+        );
+        let uuids: std::collections::HashSet<Uuid> =
+            chapter.iter().map(|slide| slide.uuid()).collect();
+        assert_eq!(uuids.len(), 4);
+        assert!(!uuids.contains(&refrain[0].uuid()));
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Reading".to_string())
+        );
+        assert_eq!(refrain.len(), 1);
+        assert!(chapter.clone().merge(refrain).is_err());
+    }
+
+    #[test]
+    fn test_map_slides() {
+        let mut chapter = numbered_chapter(3);
+        chapter.insert_slide(0, Slide::new_title_slide("Reading".to_string(), None));
+        let mut visited = Vec::new();
+
+        chapter.map_slides(|slide| {
+            visited.push(slide.uuid());
+            if let SlideContent::SingleLanguageMainContent(s) = &mut slide.slide_content {
+                s.main_text = s.main_text.to_uppercase();
+            }
+        });
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec!["Reading", "VERSE 1", "VERSE 2", "VERSE 3"]
+        );
+        assert_eq!(
+            visited,
+            chapter.iter().map(|slide| slide.uuid()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_filter_map_slides() {
+        let mut chapter = numbered_chapter(2);
+        chapter.insert_slide(0, Slide::new_title_slide("Reading".to_string(), None));
+        chapter.insert_slide(2, Slide::new_empty_slide(true));
+
+        let chapter = chapter.filter_map_slides(|mut slide| match &mut slide.slide_content {
+            SlideContent::SingleLanguageMainContent(s) => {
+                s.spoiler_text = Some(format!("After {}", s.main_text));
+                Some(slide)
+            }
+            _ => None,
+        });
+
+        assert_eq!(slide_texts(&chapter), vec!["Verse 1", "Verse 2"]);
+        assert!(chapter.iter().all(|slide| slide.has_spoiler()));
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Reading".to_string())
+        );
+    }
+
+    #[test]
+    fn test_chapter_stats() {
+        let multi = Slide::<String>::from_content(SlideContent::MultiLanguageMainContent(
+            three_language_slide(),
+        ));
+        let mut slide = english_german_slide();
+        slide.reorder_languages(&codes(&["de", "en"])).unwrap();
+        let german_english =
+            Slide::<String>::from_content(SlideContent::MultiLanguageMainContent(slide));
+        let picture = Slide::<String>::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new("cross.png".to_string()),
+        ));
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                Slide::new_content_slide("How sweet the sound".to_string(), None, None),
+                multi,
+                german_english,
+                picture,
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        assert_eq!(
+            chapter.stats(),
+            ChapterStats {
+                slide_count: 6,
+                content_slide_count: 3,
+                word_count: 24,
+                has_title: true,
+                has_pictures: true,
+                languages: codes(&["en", "de", "fr"]),
+            }
+        );
+
+        let stats = numbered_chapter(2).stats();
+        assert_eq!(stats.word_count, 4);
+        assert!(!stats.has_title);
+        assert!(!stats.has_pictures);
+        assert!(stats.languages.is_empty());
+    }
+
+    #[test]
+    fn test_ensure_title_first() {
+        let mut chapter = numbered_chapter(2);
+        chapter.ensure_title_first("Untitled");
+        assert_eq!(slide_texts(&chapter), vec!["Reading", "Verse 1", "Verse 2"]);
+        assert!(chapter[0].is_title());
+
+        chapter.ensure_title_first("Untitled");
+        assert_eq!(chapter.len(), 3);
+
+        let mut chapter = numbered_chapter(1);
+        chapter.linked_entity = LinkedEntity::Source("reading.txt".to_string());
+        chapter.ensure_title_first("Untitled");
+        assert_eq!(slide_texts(&chapter), vec!["Untitled", "Verse 1"]);
+
+        let mut chapter =
+            PresentationChapter::<String, String>::new(vec![], LinkedEntity::Title(String::new()));
+        chapter.ensure_title_first("Untitled");
+        assert_eq!(slide_texts(&chapter), vec!["Untitled"]);
+    }
+
+    #[test]
+    fn test_display_title() {
+        let mut chapter = numbered_chapter(2);
+        assert_eq!(chapter.display_title(), Some("Reading".to_string()));
+
+        chapter.insert_slide(1, Slide::new_title_slide("Psalm 23".to_string(), None));
+        assert_eq!(chapter.display_title(), Some("Psalm 23".to_string()));
+
+        chapter.remove_slide(1);
+        chapter.linked_entity = LinkedEntity::Title(String::new());
+        assert_eq!(chapter.display_title(), None);
+        chapter.linked_entity = LinkedEntity::Source("psalm.txt".to_string());
+        assert_eq!(chapter.display_title(), None);
+    }
+
+    #[test]
+    fn test_combine_short_slides() {
+        let line = |text: &str, meta_text: Option<&str>| {
+            Slide::<String>::new_content_slide(
+                text.to_string(),
+                Some(format!("After {}", text)),
+                meta_text.map(str::to_string),
+            )
+        };
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                line("Line 1", None),
+                line("Line 2", Some("Verse 1")),
+                Slide::new_content_slide("Line 3\nLine 4".to_string(), None, None),
+                line("Line 5", None).with_notes("Louder".to_string()),
+                line("Line 6", None).with_notes("Louder".to_string()),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+        let first_uuid = chapter[1].uuid();
+
+        chapter.combine_short_slides(3);
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec![
+                "Amazing Grace",
+                "Line 1\nLine 2",
+                "Line 3\nLine 4",
+                "Line 5\nLine 6",
+            ]
+        );
+        assert_eq!(chapter[1].uuid(), first_uuid);
+        assert_eq!(
+            chapter[1].slide_content,
+            SlideContent::SingleLanguageMainContent(SingleLanguageMainContentSlide {
+                main_text: "Line 1\nLine 2".to_string(),
+                spoiler_text: Some("After Line 2".to_string()),
+                meta_text: Some("Verse 1".to_string()),
+                ..Default::default()
+            })
+        );
+        assert_eq!(chapter[3].notes, Some("Louder".to_string()));
+    }
+
+    #[test]
+    fn test_combine_short_slides_moves_chords() {
+        let mut first = verse_with_lines(1);
+        first.chords = Some(vec![chord(2, "G")]);
+        let mut second = verse_with_lines(1);
+        second.chords = Some(vec![chord(0, "C")]);
+        let slides = [first, second]
+            .map(|content| Slide::from_content(SlideContent::SingleLanguageMainContent(content)));
+        let mut chapter = PresentationChapter::<String, String>::new(
+            slides.to_vec(),
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        chapter.combine_short_slides(2);
+
+        assert_eq!(chapter.len(), 1);
+        let SlideContent::SingleLanguageMainContent(combined) = &chapter[0].slide_content else {
+            panic!("the slides should have been combined");
+        };
+        assert_eq!(combined.chords_at_line(1)[0].chord, "C");
+        assert_eq!(combined.chords, Some(vec![chord(2, "G"), chord(7, "C")]));
+    }
+
+    #[test]
+    fn test_load_from_json() {
+        let chapter = numbered_chapter(2);
+        let json = chapter.to_json_pretty().unwrap();
+
+        assert_eq!(
+            PresentationChapter::<String, String>::load_from_json(&json).unwrap(),
+            chapter
+        );
+    }
+
+    #[test]
+    fn test_load_truncated_json() {
+        let json = numbered_chapter(2).to_json_pretty().unwrap();
+        let truncated = &json[..json.len() / 2];
+
+        let error = PresentationChapter::<String, String>::load_from_json(truncated).unwrap_err();
+
+        let SlideError::UnexpectedEnd { location } = error else {
+            panic!("expected an unexpected end, got {:?}", error);
+        };
+        assert_eq!(location.line, truncated.lines().count());
+        assert_eq!(location.offset, truncated.len());
+        assert!(
+            error
+                .to_string()
+                .contains(&format!("line {}", location.line))
+        );
+    }
+
+    #[test]
+    fn test_load_invalid_json() {
+        let error = PresentationChapter::<String, String>::load_from_json("{\n  \"slides\": [}\n}")
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            SlideError::Syntax {
+                location: JsonLocation {
+                    line: 2,
+                    column: 14,
+                    offset: 15
+                },
+                ..
+            }
+        ));
+
+        let error = PresentationChapter::<String, String>::load_from_json(
+            r#"{"slides": [], "schema_version": 1}"#,
+        )
+        .unwrap_err();
+        let SlideError::Data {
+            expected_field,
+            message,
+            ..
+        } = error
+        else {
+            panic!("expected a data error, got {:?}", error);
+        };
+        assert_eq!(expected_field, Some("linked_entity".to_string()));
+        assert_eq!(message, "missing field `linked_entity`");
+    }
+
+    #[test]
+    fn test_chunk_slides() {
+        let chapter = numbered_chapter(6);
+
+        let chunks = chapter.chunk_slides(3);
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|chunk| chunk.len() == 3));
+        assert_eq!(chunks[1][0].to_string(), "Verse 4");
+
+        let chunks = chapter.chunk_slides(4);
+        assert_eq!(
+            chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>(),
+            vec![4, 2]
+        );
+        assert_eq!(chunks[1][1].to_string(), "Verse 6");
+
+        assert!(numbered_chapter(0).chunk_slides(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunk_slides_of_size_zero() {
+        let _ = numbered_chapter(2).chunk_slides(0);
+    }
+
+    #[test]
+    fn test_sort_slides_by_duration() {
+        let mut chapter = numbered_chapter(6);
+        for (index, duration_millis) in [Some(5000), None, Some(1000), None, Some(5000), Some(0)]
+            .into_iter()
+            .enumerate()
+        {
+            chapter.slides[index].duration_millis = duration_millis;
+        }
+
+        chapter.sort_slides_by_duration();
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec![
+                "Verse 6", "Verse 3", "Verse 1", "Verse 5", "Verse 2", "Verse 4"
+            ]
+        );
+        assert_eq!(chapter[5].duration_millis, None);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_clone_with_new_ids() {
+        let mut chapter = chapter_with_ids(&["one", "two"]);
+        chapter.slides.push(Slide::new_empty_slide(true));
+
+        let clone = chapter.clone_with_new_ids();
+
+        assert_eq!(clone.linked_entity, chapter.linked_entity);
+        assert_eq!(clone.len(), chapter.len());
+        for (cloned_slide, slide) in clone.slides.iter().zip(&chapter.slides) {
+            assert!(cloned_slide.content_eq(slide));
+            assert_ne!(cloned_slide.uuid(), slide.uuid());
+            assert!(cloned_slide.id.is_some());
+            assert_ne!(cloned_slide.id, slide.id);
+        }
+        assert!(clone.duplicate_ids().is_empty());
+    }
+
+    #[test]
+    fn test_content_fingerprint() {
+        let chapter = numbered_chapter(3);
+        let mut with_media = chapter.clone();
+        with_media.slides[0].id = Some("verse-1".to_string());
+        with_media.slides[1].linked_file = Some("verse.mp3".to_string());
+
+        assert_eq!(
+            chapter.content_fingerprint(),
+            with_media.content_fingerprint()
+        );
+        assert_eq!(
+            chapter.content_fingerprint(),
+            numbered_chapter(3).content_fingerprint()
+        );
+
+        let mut changed = chapter.clone();
+        changed.slides[2] = Slide::new_content_slide("Verse 3!".to_string(), None, None);
+        assert_ne!(chapter.content_fingerprint(), changed.content_fingerprint());
+
+        let mut timed = chapter.clone();
+        timed.slides[0].duration_millis = Some(1000);
+        assert_ne!(chapter.content_fingerprint(), timed.content_fingerprint());
+    }
+
+    #[test]
+    fn test_fnv1a_reference_value() {
+        let mut hasher = Fnv1a::default();
+        hasher.write(b"a");
+
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_content_fingerprint_is_fixed() {
+        // The same value is expected with and without the `tagged-content` feature
+        assert_eq!(
+            numbered_chapter(3).content_fingerprint(),
+            0xea9d_5de3_85f3_8825
+        );
+    }
+
+    #[test]
+    fn test_title_to_content() {
+        let mut slide = Slide::<String>::new_title_slide(
+            "Amazing Grace".to_string(),
+            Some("John Newton".to_string()),
+        );
+
+        slide.title_to_content();
+
+        assert_eq!(
+            slide.slide_content,
+            Slide::<String>::new_content_slide(
+                "Amazing Grace".to_string(),
+                None,
+                Some("John Newton".to_string())
+            )
+            .slide_content
+        );
+    }
+
+    #[test]
+    fn test_content_to_title() {
+        let mut slide = Slide::<String>::new_content_slide(
+            "Amazing Grace".to_string(),
+            Some("How sweet the sound".to_string()),
+            Some("John Newton".to_string()),
+        );
+
+        slide.content_to_title();
+
+        assert_eq!(
+            slide.slide_content,
+            SlideContent::Title(TitleSlide {
+                title_text: "Amazing Grace".to_string(),
+                meta_text: Some("John Newton".to_string()),
+            })
+        );
+
+        slide.title_to_content();
+        slide.content_to_title();
+        assert_eq!(slide.kind(), SlideContentKind::Title);
+    }
+
+    #[test]
+    fn test_title_content_conversion_of_empty_slide() {
+        let mut slide = Slide::<String>::new_empty_slide(false);
+
+        slide.title_to_content();
+        slide.content_to_title();
+
+        assert_eq!(
+            slide.slide_content,
+            SlideContent::Empty(EmptySlide {
+                black_background: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_count_single_language() {
+        let slide = Slide::<String>::new_content_slide(
+            "Amazing grace\nHow sweet the sound\nThat saved a wretch like me".to_string(),
+            Some("I once was lost".to_string()),
+            Some("Verse 1".to_string()),
+        );
+
+        assert_eq!(slide.slide_content.line_count(), 5);
+        assert_eq!(
+            Slide::<String>::new_empty_slide(true)
+                .slide_content
+                .line_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_line_count_multi_language() {
+        let content = SlideContent::MultiLanguageMainContent(MultiLanguageMainContentSlide {
+            main_text_list: vec![
+                "Amazing grace\nHow sweet the sound".to_string(),
+                "Erstaunliche Gnade\nWie süß der Klang".to_string(),
+            ],
+            spoiler_text_vector: vec!["That saved a wretch like me".to_string(), String::new()],
+            meta_text: Some("Verse 1".to_string()),
+            ..english_german_slide()
+        });
+
+        assert_eq!(content.line_count(), 6);
+
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::from_content(content),
+                Slide::new_content_slide("One\nTwo".to_string(), None, None),
+                Slide::from_content(SlideContent::SimplePicture(SimplePictureSlide::new(
+                    "cross.png".to_string(),
+                ))),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+        assert_eq!(chapter.total_line_count(), 8);
+    }
+
+    #[test]
+    fn test_split_by_chars_under_budget() {
+        let verse = verse_with_lines(3);
+        let budget = verse.main_text.chars().count();
+
+        assert_eq!(verse.split_by_chars(budget), vec![verse.clone()]);
+        assert_eq!(verse.split_by_chars(0), vec![verse]);
+    }
+
+    #[test]
+    fn test_split_by_chars() {
+        let verse = SingleLanguageMainContentSlide {
+            main_text: "Amazing grace\nhow sweet\nthe sound that saved a wretch\nlike me"
+                .to_string(),
+            spoiler_text: Some("I once was lost".to_string()),
+            meta_text: Some("Verse 1".to_string()),
+            ..Default::default()
+        };
+
+        let parts = verse.split_by_chars(24);
+
+        assert_eq!(
+            parts
+                .iter()
+                .map(|part| part.main_text.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "Amazing grace\nhow sweet",
+                "the sound that saved a wretch",
+                "like me"
+            ]
+        );
+        assert!(
+            parts
+                .iter()
+                .all(|part| part.meta_text.as_deref() == Some("Verse 1"))
+        );
+        assert_eq!(parts[0].spoiler_text, None);
+        assert_eq!(parts[1].spoiler_text, None);
+        assert_eq!(parts[2].spoiler_text, verse.spoiler_text);
+    }
+
+    #[test]
+    fn test_into_single_chapter() {
+        let presentation = Presentation::<String, String>::new(
+            vec![
+                numbered_chapter(2),
+                numbered_chapter(1),
+                numbered_chapter(3),
+            ],
+            Some("Sunday Service".to_string()),
+        );
+        let uuids: Vec<Uuid> = presentation
+            .chapters
+            .iter()
+            .flat_map(|chapter| chapter.slides.iter().map(Slide::uuid))
+            .collect();
+
+        let chapter =
+            presentation.into_single_chapter(LinkedEntity::Title("Sunday Service".to_string()));
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec![
+                "Verse 1", "Verse 2", "Verse 1", "Verse 1", "Verse 2", "Verse 3"
+            ]
+        );
+        assert_eq!(
+            chapter.slides.iter().map(Slide::uuid).collect::<Vec<_>>(),
+            uuids
+        );
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Sunday Service".to_string())
+        );
+    }
+
+    #[test]
+    fn test_iter_slides() {
+        let presentation = Presentation::<String, String>::new(
+            vec![
+                numbered_chapter(2),
+                numbered_chapter(0),
+                numbered_chapter(1),
+            ],
+            None,
+        );
+
+        let positions: Vec<(usize, usize, Uuid)> = presentation
+            .iter_slides()
+            .map(|(chapter_index, slide_index, slide)| (chapter_index, slide_index, slide.uuid()))
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (0, 0, presentation.chapters[0][0].uuid()),
+                (0, 1, presentation.chapters[0][1].uuid()),
+                (2, 0, presentation.chapters[2][0].uuid()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_markdown_export_line_breaks() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![Slide::new_content_slide(
+                "Amazing grace\nHow sweet the sound\n\nI once was lost\nBut now am found"
+                    .to_string(),
+                None,
+                None,
+            )],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        assert_eq!(
+            chapter.to_markdown(),
+            "Amazing grace\\\n\
+             How sweet the sound\n\
+             \n\
+             I once was lost\\\n\
+             But now am found\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_export_escaping() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("#1 *Hits*".to_string(), None),
+                Slide::new_content_slide(
+                    "- God_is [good] <all> the time\\".to_string(),
+                    None,
+                    Some("Psalm 100".to_string()),
+                ),
+            ],
+            LinkedEntity::Title("Hits".to_string()),
+        );
+
+        assert_eq!(
+            chapter.to_markdown(),
+            "# \\#1 \\*Hits\\*\n\
+             \n\
+             \\- God\\_is \\[good\\] \\<all\\> the time\\\\\n\
+             \n\
+             *Psalm 100*\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_export_countdown() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![Slide::from_content(SlideContent::Countdown(
+                CountdownSlide {
+                    target_seconds: 300,
+                    message: Some("The service starts soon".to_string()),
+                    black_background: true,
+                },
+            ))],
+            LinkedEntity::Title("Countdown".to_string()),
+        );
+
+        assert_eq!(
+            chapter.to_markdown(),
+            "**5:00**\n\nThe service starts soon\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_spoilers_keeps_spoiler_before_empty_line() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_content_slide(
+                    "Amazing grace".to_string(),
+                    Some("Instrumental".to_string()),
+                    None,
+                ),
+                Slide::new_content_slide(String::new(), None, None),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        chapter.generate_spoilers();
+
+        assert_eq!(
+            chapter.slides[0].slide_content,
+            Slide::<String>::new_content_slide(
+                "Amazing grace".to_string(),
+                Some("Instrumental".to_string()),
+                None
+            )
+            .slide_content
+        );
+    }
+
+    #[test]
+    fn test_generate_spoilers_multi_language() {
+        let multi = |english: &str, german: &str| {
+            let mut slide = SingleLanguageMainContentSlide::new(english.to_string(), None, None)
+                .into_multi_language("en".to_string());
+            slide.add_language("de".to_string(), german.to_string(), None);
+            Slide::<String>::from_content(SlideContent::MultiLanguageMainContent(slide))
+        };
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_content_slide("Intro".to_string(), None, None),
+                multi("Amazing grace\nHow sweet", "Erstaunliche Gnade\nWie süß"),
+                Slide::new_empty_slide(true),
+                multi("I once was lost", "Ich war verloren"),
+                Slide::new_content_slide("Amen".to_string(), None, None),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        chapter.generate_spoilers();
+
+        let SlideContent::SingleLanguageMainContent(intro) = &chapter[0].slide_content else {
+            panic!("The intro is a single-language slide");
+        };
+        assert_eq!(intro.spoiler_text.as_deref(), Some("Amazing grace"));
+        let SlideContent::MultiLanguageMainContent(first) = &chapter[1].slide_content else {
+            panic!("The first verse is a multi-language slide");
+        };
+        assert_eq!(
+            first.spoiler_text_vector,
+            codes(&["I once was lost", "Ich war verloren"])
+        );
+        let SlideContent::MultiLanguageMainContent(second) = &chapter[3].slide_content else {
+            panic!("The second verse is a multi-language slide");
+        };
+        assert_eq!(second.spoiler_text_vector, codes(&["", ""]));
+    }
+
+    #[test]
+    fn test_markdown_export_picture_paths() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::from_content(SlideContent::SimplePicture(
+                    SimplePictureSlide::new("/pictures/old cross (1).png".to_string())
+                        .with_alt_text("A cross [old]".to_string()),
+                )),
+                Slide::new_video_slide("intro video.mp4".to_string(), false, false),
+            ],
+            LinkedEntity::Title("Pictures".to_string()),
+        );
+
+        assert_eq!(
+            chapter.to_markdown(),
+            "![A cross \\[old\\]](</pictures/old cross (1).png>)\n\
+             \n\
+             [video](<intro video.mp4>)\n"
+        );
+    }
 }
@@ -0,0 +1,71 @@
+use super::*;
+use ::bincode::error::{DecodeError, EncodeError};
+use ::bincode::{Decode, Encode};
+
+/// Serializes a chapter to the compact binary format of `bincode`.
+/// The format is not self-describing, so it is meant for caches rather than for exchange.
+pub fn to_bincode<T, M>(chapter: &PresentationChapter<T, M>) -> Result<Vec<u8>, EncodeError>
+where
+    T: Encode,
+    M: Encode,
+{
+    ::bincode::encode_to_vec(chapter, ::bincode::config::standard())
+}
+
+/// Deserializes a chapter which has been serialized with [`to_bincode`]
+pub fn from_bincode<T, M>(bytes: &[u8]) -> Result<PresentationChapter<T, M>, DecodeError>
+where
+    T: Decode<()>,
+    M: Decode<()>,
+{
+    let (chapter, _) = ::bincode::decode_from_slice(bytes, ::bincode::config::standard())?;
+    Ok(chapter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let mut picture = Slide::new_empty_slide(false)
+            .with_media("cross.png".to_string())
+            .with_background(Background::Color {
+                rgba: [0, 0, 0, 255],
+            });
+        picture.slide_content = SlideContent::SimplePicture(
+            SimplePictureSlide::new("cross.png".to_string()).with_dimensions(1920, 1080),
+        );
+        let mut multi = Slide::new_empty_slide(false).with_transition(Transition::Slide {
+            direction: Direction::Left,
+            millis: 300,
+        });
+        multi.slide_content = SlideContent::MultiLanguageMainContent(
+            SingleLanguageMainContentSlide::default().into_multi_language("en".to_string()),
+        );
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                Slide::new_content_slide(
+                    "Amazing grace".to_string(),
+                    Some("How sweet the sound".to_string()),
+                    Some("Verse 1".to_string()),
+                )
+                .with_notes("Slowly".to_string()),
+                picture,
+                multi,
+                Slide::new_video_slide("intro.mp4".to_string(), true, false),
+                Slide::new_countdown_slide(300, Some("Welcome".to_string())),
+            ],
+            LinkedEntity::Media("amazing-grace".to_string()),
+        );
+        chapter.slides[0].id = Some("title".to_string());
+
+        let bytes = to_bincode(&chapter).unwrap();
+        let deserialized: PresentationChapter<String, String> = from_bincode(&bytes).unwrap();
+
+        assert_eq!(deserialized, chapter);
+        assert!(bytes.len() < chapter.to_json_compact().unwrap().len());
+        assert!(from_bincode::<String, String>(&bytes[..bytes.len() / 2]).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+use super::*;
+
+/// A builder for assembling a [`PresentationChapter`] slide by slide.
+/// If no linked entity is set, the chapter is linked to an empty title.
+///
+/// # Generic Parameters
+/// T: The linked entity (Song, BibleVerse, etc.)
+/// M: The media type (SongFile, PathBuf, etc.)
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChapterBuilder<T, M> {
+    slides: Vec<Slide<M>>,
+    linked_entity: Option<LinkedEntity<T, M>>,
+}
+
+impl<T, M> Default for ChapterBuilder<T, M> {
+    fn default() -> Self {
+        Self {
+            slides: Vec::new(),
+            linked_entity: None,
+        }
+    }
+}
+
+impl<T, M> ChapterBuilder<T, M>
+where
+    T: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an already constructed slide
+    pub fn slide(mut self, slide: Slide<M>) -> Self {
+        self.slides.push(slide);
+        self
+    }
+
+    pub fn title_slide(self, title_text: String, meta_text: Option<String>) -> Self {
+        self.slide(Slide::new_title_slide(title_text, meta_text))
+    }
+
+    pub fn content_slide(
+        self,
+        main_text: String,
+        spoiler_text: Option<String>,
+        meta_text: Option<String>,
+    ) -> Self {
+        self.slide(Slide::new_content_slide(main_text, spoiler_text, meta_text))
+    }
+
+    pub fn empty_slide(self, black_background: bool) -> Self {
+        self.slide(Slide::new_empty_slide(black_background))
+    }
+
+    pub fn linked_entity(mut self, linked_entity: LinkedEntity<T, M>) -> Self {
+        self.linked_entity = Some(linked_entity);
+        self
+    }
+
+    pub fn build(self) -> PresentationChapter<T, M> {
+        PresentationChapter::new(
+            self.slides,
+            self.linked_entity
+                .unwrap_or_else(|| LinkedEntity::Title(String::new())),
+        )
+    }
+}
+
+/// A builder for a single [`Slide`] with its optional metadata.
+/// Exactly one content method (`content`, `title`, `empty` or `picture`) is meant to be called,
+/// a later call replaces the content of an earlier one. Without any content, an empty
+/// slide with a non-black background is built.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SlideBuilder<M> {
+    slide: Option<Slide<M>>,
+    linked_file: Option<M>,
+    notes: Option<String>,
+    duration_millis: Option<u32>,
+    transition: Option<Transition>,
+}
+
+impl<M> Default for SlideBuilder<M> {
+    fn default() -> Self {
+        Self {
+            slide: None,
+            linked_file: None,
+            notes: None,
+            duration_millis: None,
+            transition: None,
+        }
+    }
+}
+
+impl<M> SlideBuilder<M>
+where
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn content(
+        mut self,
+        main_text: String,
+        spoiler_text: Option<String>,
+        meta_text: Option<String>,
+    ) -> Self {
+        self.slide = Some(Slide::new_content_slide(main_text, spoiler_text, meta_text));
+        self
+    }
+
+    pub fn title(mut self, title_text: String, meta_text: Option<String>) -> Self {
+        self.slide = Some(Slide::new_title_slide(title_text, meta_text));
+        self
+    }
+
+    pub fn empty(mut self, black_background: bool) -> Self {
+        self.slide = Some(Slide::new_empty_slide(black_background));
+        self
+    }
+
+    pub fn picture(mut self, picture_path: String) -> Self {
+        self.slide = Some(Slide::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new(picture_path),
+        )));
+        self
+    }
+
+    pub fn media(mut self, media: M) -> Self {
+        self.linked_file = Some(media);
+        self
+    }
+
+    pub fn notes(mut self, notes: String) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    pub fn duration(mut self, duration_millis: u32) -> Self {
+        self.duration_millis = Some(duration_millis);
+        self
+    }
+
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    pub fn build(self) -> Slide<M> {
+        let mut slide = self.slide.unwrap_or_else(|| Slide::new_empty_slide(false));
+        slide.linked_file = self.linked_file;
+        slide.notes = self.notes;
+        slide.duration_millis = self.duration_millis;
+        slide.transition = self.transition;
+        slide
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_matches_hand_built_chapter() {
+        let built = ChapterBuilder::<String, String>::new()
+            .title_slide("Amazing Grace".to_string(), Some("John Newton".to_string()))
+            .content_slide(
+                "Amazing grace, how sweet the sound".to_string(),
+                Some("That saved a wretch like me".to_string()),
+                None,
+            )
+            .empty_slide(true)
+            .linked_entity(LinkedEntity::Title("Amazing Grace".to_string()))
+            .build();
+
+        let hand_built = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Amazing Grace".to_string(),
+                    Some("John Newton".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Amazing grace, how sweet the sound".to_string(),
+                    Some("That saved a wretch like me".to_string()),
+                    None,
+                ),
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        // Every slide gets its own UUID, so only the content can be compared
+        assert_eq!(built.linked_entity, hand_built.linked_entity);
+        assert_eq!(built.len(), 3);
+        for (built_slide, hand_built_slide) in built.slides.iter().zip(&hand_built.slides) {
+            assert_eq!(built_slide.slide_content, hand_built_slide.slide_content);
+            assert_eq!(built_slide.linked_file, hand_built_slide.linked_file);
+        }
+    }
+
+    #[test]
+    fn test_builder_default_linked_entity() {
+        let chapter = ChapterBuilder::<String, String>::new()
+            .empty_slide(false)
+            .build();
+
+        assert_eq!(chapter.linked_entity, LinkedEntity::Title(String::new()));
+        assert_eq!(chapter.len(), 1);
+    }
+
+    #[test]
+    fn test_slide_builder_fully_configured() {
+        let slide = SlideBuilder::new()
+            .content(
+                " Amazing grace, how sweet the sound ".to_string(),
+                Some("That saved a wretch like me".to_string()),
+                Some("Verse 1".to_string()),
+            )
+            .media("grace.mp3".to_string())
+            .notes("Piano only".to_string())
+            .duration(8000)
+            .transition(Transition::Fade { millis: 300 })
+            .build();
+
+        assert_eq!(
+            slide.slide_content,
+            Slide::<String>::new_content_slide(
+                "Amazing grace, how sweet the sound".to_string(),
+                Some("That saved a wretch like me".to_string()),
+                Some("Verse 1".to_string()),
+            )
+            .slide_content
+        );
+        assert_eq!(slide.linked_file, Some("grace.mp3".to_string()));
+        assert_eq!(slide.notes, Some("Piano only".to_string()));
+        assert_eq!(slide.duration_millis, Some(8000));
+        assert_eq!(slide.transition, Some(Transition::Fade { millis: 300 }));
+    }
+
+    #[test]
+    fn test_slide_builder_without_content() {
+        let slide = SlideBuilder::<String>::new()
+            .notes("Wait for the pastor".to_string())
+            .build();
+
+        assert_eq!(
+            slide.slide_content,
+            SlideContent::Empty(EmptySlide {
+                black_background: false
+            })
+        );
+        assert!(slide.has_notes());
+    }
+
+    #[test]
+    fn test_slide_builder_picture() {
+        let slide = SlideBuilder::<String>::new()
+            .title("Replaced".to_string(), None)
+            .picture("cross.png".to_string())
+            .build();
+
+        assert_eq!(
+            slide.slide_content,
+            SlideContent::SimplePicture(SimplePictureSlide::new("cross.png".to_string()))
+        );
+    }
+}
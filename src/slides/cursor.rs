@@ -0,0 +1,190 @@
+use super::*;
+
+/// A cursor for stepping through the slides of a [`Presentation`], e.g. in a live view.
+/// Stepping past the last slide of a chapter moves to the first slide of the next chapter,
+/// chapters without slides are skipped. At the first and last slide of the presentation the
+/// cursor stays where it is.
+///
+/// # Generic Parameters
+/// T: The linked entity (Song, BibleVerse, etc.)
+/// M: The media type (SongFile, PathBuf, etc.)
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cursor<'a, T, M> {
+    presentation: &'a Presentation<T, M>,
+    position: Option<(usize, usize)>,
+}
+
+impl<'a, T, M> Cursor<'a, T, M> {
+    /// Creates a cursor on the first slide of the presentation.
+    /// A presentation without any slides has no current slide.
+    pub fn new(presentation: &'a Presentation<T, M>) -> Self {
+        let position = presentation
+            .chapters
+            .iter()
+            .position(|chapter| !chapter.slides.is_empty())
+            .map(|chapter_index| (chapter_index, 0));
+        Self {
+            presentation,
+            position,
+        }
+    }
+
+    /// Returns the chapter index and slide index of the current slide
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+
+    /// Returns the current slide together with its chapter
+    pub fn current(&self) -> Option<(&'a PresentationChapter<T, M>, &'a Slide<M>)> {
+        let (chapter_index, slide_index) = self.position?;
+        let chapter = &self.presentation.chapters[chapter_index];
+        Some((chapter, &chapter.slides[slide_index]))
+    }
+
+    /// Moves to the next slide. Returns `false` if the cursor is already on the last slide.
+    // The cursor moves in both directions and is not consumed, so it is no iterator
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        let Some((chapter_index, slide_index)) = self.position else {
+            return false;
+        };
+        let next_position =
+            if slide_index + 1 < self.presentation.chapters[chapter_index].slides.len() {
+                Some((chapter_index, slide_index + 1))
+            } else {
+                self.presentation
+                    .chapters
+                    .iter()
+                    .enumerate()
+                    .skip(chapter_index + 1)
+                    .find(|(_, chapter)| !chapter.slides.is_empty())
+                    .map(|(next_chapter_index, _)| (next_chapter_index, 0))
+            };
+        self.move_to(next_position)
+    }
+
+    /// Moves to the previous slide. Returns `false` if the cursor is already on the first slide.
+    pub fn prev(&mut self) -> bool {
+        let Some((chapter_index, slide_index)) = self.position else {
+            return false;
+        };
+        let previous_position = if slide_index > 0 {
+            Some((chapter_index, slide_index - 1))
+        } else {
+            self.presentation.chapters[..chapter_index]
+                .iter()
+                .rposition(|chapter| !chapter.slides.is_empty())
+                .map(|previous_chapter_index| {
+                    let chapter = &self.presentation.chapters[previous_chapter_index];
+                    (previous_chapter_index, chapter.slides.len() - 1)
+                })
+        };
+        self.move_to(previous_position)
+    }
+
+    /// Moves to the given slide of the given chapter.
+    /// Returns `false` and keeps the current position if there is no such slide.
+    pub fn goto(&mut self, chapter_index: usize, slide_index: usize) -> bool {
+        let exists = self
+            .presentation
+            .chapters
+            .get(chapter_index)
+            .is_some_and(|chapter| slide_index < chapter.slides.len());
+        self.move_to(exists.then_some((chapter_index, slide_index)))
+    }
+
+    fn move_to(&mut self, position: Option<(usize, usize)>) -> bool {
+        match position {
+            Some(position) => {
+                self.position = Some(position);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presentation(slide_counts: &[usize]) -> Presentation<String, String> {
+        Presentation::new(
+            slide_counts
+                .iter()
+                .enumerate()
+                .map(|(chapter_index, &slide_count)| {
+                    PresentationChapter::new(
+                        (1..=slide_count)
+                            .map(|i| Slide::new_content_slide(format!("Verse {}", i), None, None))
+                            .collect(),
+                        LinkedEntity::Title(format!("Song {}", chapter_index + 1)),
+                    )
+                })
+                .collect(),
+            None,
+        )
+    }
+
+    fn current_text(cursor: &Cursor<String, String>) -> Option<String> {
+        cursor.current().map(|(chapter, slide)| {
+            format!(
+                "{}: {}",
+                chapter.display_title().unwrap_or_default(),
+                slide.slide_content
+            )
+        })
+    }
+
+    #[test]
+    fn test_cursor_forward_across_chapters() {
+        let presentation = presentation(&[2, 0, 1]);
+        let mut cursor = presentation.cursor();
+
+        assert_eq!(current_text(&cursor).as_deref(), Some("Song 1: Verse 1"));
+        assert!(cursor.next());
+        assert_eq!(cursor.position(), Some((0, 1)));
+        assert!(cursor.next());
+        assert_eq!(cursor.position(), Some((2, 0)));
+        assert_eq!(current_text(&cursor).as_deref(), Some("Song 3: Verse 1"));
+        assert!(!cursor.next());
+        assert_eq!(cursor.position(), Some((2, 0)));
+    }
+
+    #[test]
+    fn test_cursor_backward_across_chapters() {
+        let presentation = presentation(&[2, 0, 1]);
+        let mut cursor = presentation.cursor();
+        assert!(cursor.goto(2, 0));
+
+        assert!(cursor.prev());
+        assert_eq!(cursor.position(), Some((0, 1)));
+        assert_eq!(current_text(&cursor).as_deref(), Some("Song 1: Verse 2"));
+        assert!(cursor.prev());
+        assert_eq!(cursor.position(), Some((0, 0)));
+        assert!(!cursor.prev());
+        assert_eq!(cursor.position(), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_cursor_goto() {
+        let presentation = presentation(&[2, 0, 1]);
+        let mut cursor = presentation.cursor();
+
+        assert!(cursor.goto(0, 1));
+        assert!(!cursor.goto(1, 0));
+        assert!(!cursor.goto(3, 0));
+        assert_eq!(cursor.position(), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_cursor_without_slides() {
+        let presentation = presentation(&[0, 0]);
+        let mut cursor = presentation.cursor();
+
+        assert_eq!(cursor.current(), None);
+        assert!(!cursor.next());
+        assert!(!cursor.prev());
+        assert_eq!(cursor.position(), None);
+    }
+}
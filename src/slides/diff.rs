@@ -0,0 +1,134 @@
+use super::*;
+
+/// The difference of a single slide position between two chapters
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SlideDiff {
+    /// A slide exists at this index only in the new chapter
+    Added(usize),
+
+    /// A slide exists at this index only in the old chapter
+    Removed(usize),
+
+    /// The slides at this index differ
+    Changed { index: usize },
+
+    /// The slides at this index are equal
+    Unchanged(usize),
+}
+
+/// Compares two chapters slide by slide.
+/// The comparison is index-aligned, so inserting a slide marks all following slides as changed.
+pub fn diff_chapters<T, M>(
+    old: &PresentationChapter<T, M>,
+    new: &PresentationChapter<T, M>,
+) -> Vec<SlideDiff>
+where
+    M: PartialEq,
+{
+    (0..old.slides.len().max(new.slides.len()))
+        .map(
+            |index| match (old.slides.get(index), new.slides.get(index)) {
+                (Some(old_slide), Some(new_slide)) if old_slide == new_slide => {
+                    SlideDiff::Unchanged(index)
+                }
+                (Some(_), Some(_)) => SlideDiff::Changed { index },
+                (None, Some(_)) => SlideDiff::Added(index),
+                (Some(_), None) => SlideDiff::Removed(index),
+                (None, None) => unreachable!("index is within the longer chapter"),
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapter(verses: &[&str]) -> PresentationChapter<String, String> {
+        PresentationChapter::new(
+            verses
+                .iter()
+                .map(|v| Slide::new_content_slide(v.to_string(), None, None))
+                .collect(),
+            LinkedEntity::Title("Song".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_diff_identical_chapters() {
+        let old = chapter(&["Verse 1", "Verse 2"]);
+
+        assert_eq!(
+            diff_chapters(&old, &old.clone()),
+            vec![SlideDiff::Unchanged(0), SlideDiff::Unchanged(1)]
+        );
+    }
+
+    #[test]
+    fn test_diff_insertion_at_end() {
+        let old = chapter(&["Verse 1", "Verse 2"]);
+        let mut new = old.clone();
+        new.slides
+            .push(Slide::new_content_slide("Verse 3".to_string(), None, None));
+
+        assert_eq!(
+            diff_chapters(&old, &new),
+            vec![
+                SlideDiff::Unchanged(0),
+                SlideDiff::Unchanged(1),
+                SlideDiff::Added(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_insertion_at_front() {
+        let old = chapter(&["Verse 1", "Verse 2"]);
+        let mut new = old.clone();
+        new.slides
+            .insert(0, Slide::new_title_slide("Title".to_string(), None));
+
+        assert_eq!(
+            diff_chapters(&old, &new),
+            vec![
+                SlideDiff::Changed { index: 0 },
+                SlideDiff::Changed { index: 1 },
+                SlideDiff::Added(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_deletion() {
+        let old = chapter(&["Verse 1", "Verse 2", "Verse 3"]);
+        let mut new = old.clone();
+        new.slides.pop();
+
+        assert_eq!(
+            diff_chapters(&old, &new),
+            vec![
+                SlideDiff::Unchanged(0),
+                SlideDiff::Unchanged(1),
+                SlideDiff::Removed(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_modification_in_the_middle() {
+        let old = chapter(&["Verse 1", "Verse 2", "Verse 3"]);
+        let mut new = old.clone();
+        new.slides[1].slide_content =
+            Slide::<String>::new_content_slide("Verse 2 (corrected)".to_string(), None, None)
+                .slide_content;
+
+        assert_eq!(
+            diff_chapters(&old, &new),
+            vec![
+                SlideDiff::Unchanged(0),
+                SlideDiff::Changed { index: 1 },
+                SlideDiff::Unchanged(2)
+            ]
+        );
+    }
+}
@@ -0,0 +1,39 @@
+/// Self-contained HTML export for previews in a browser
+pub mod html;
+
+/// reveal.js slide decks, e.g. for streamed services
+pub mod revealjs;
+
+/// OpenLyrics XML export for the interoperability with OpenLP
+#[cfg(feature = "openlyrics")]
+pub mod openlyrics;
+
+/// Escapes the characters which have a special meaning in HTML and XML
+pub(crate) fn escape_markup(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_markup() {
+        assert_eq!(
+            escape_markup(r#"<b>"Tom & Jerry's"</b>"#),
+            "&lt;b&gt;&quot;Tom &amp; Jerry&#39;s&quot;&lt;/b&gt;"
+        );
+        assert_eq!(escape_markup("Amazing grace"), "Amazing grace");
+    }
+}
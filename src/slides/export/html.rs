@@ -0,0 +1,144 @@
+use super::escape_markup;
+use crate::slides::*;
+
+const STYLE: &str = "body { margin: 0; background: #333; font-family: sans-serif; }
+section { box-sizing: border-box; width: 100vw; height: 100vh; display: flex; flex-direction: column; align-items: center; justify-content: center; background: #000; color: #fff; text-align: center; }
+section.empty { background: #fff; }
+section.empty.black { background: #000; }
+h1 { font-size: 4em; }
+p { font-size: 2.5em; margin: 0.3em; }
+p.spoiler { font-size: 1.5em; color: #aaa; }
+p.meta { font-size: 1em; color: #888; }
+img, video { max-width: 100%; max-height: 100%; }";
+
+/// Renders a presentation chapter as a self-contained HTML document with one `<section>` per slide
+pub fn chapter_to_html<T, M>(chapter: &PresentationChapter<T, M>) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<style>\n");
+    html.push_str(STYLE);
+    html.push_str("\n</style>\n</head>\n<body>\n");
+    for slide in &chapter.slides {
+        html.push_str(&slide_to_html(&slide.slide_content));
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn slide_to_html(content: &SlideContent) -> String {
+    let class = match content {
+        SlideContent::Empty(s) if s.black_background => " class=\"empty black\"",
+        SlideContent::Empty(_) => " class=\"empty\"",
+        _ => "",
+    };
+    format!("<section{}>\n{}</section>\n", class, slide_body(content))
+}
+
+/// Renders the elements of a slide without the surrounding `<section>`
+pub(super) fn slide_body(content: &SlideContent) -> String {
+    let mut body = String::new();
+    match content {
+        SlideContent::SingleLanguageMainContent(s) => {
+            body.push_str(&paragraph(&s.main_text, None));
+            if let Some(spoiler_text) = &s.spoiler_text {
+                body.push_str(&paragraph(spoiler_text, Some("spoiler")));
+            }
+        }
+        SlideContent::Title(s) => {
+            body.push_str(&format!("<h1>{}</h1>\n", text_with_breaks(&s.title_text)));
+        }
+        SlideContent::MultiLanguageMainContent(s) => {
+            for main_text in &s.main_text_list {
+                body.push_str(&paragraph(main_text, None));
+            }
+            for spoiler_text in s.spoiler_text_vector.iter().filter(|t| !t.is_empty()) {
+                body.push_str(&paragraph(spoiler_text, Some("spoiler")));
+            }
+        }
+        SlideContent::SimplePicture(s) => {
+            body.push_str(&format!(
+                "<img src=\"{}\" alt=\"{}\">\n",
+                escape_markup(&s.picture_path),
+                escape_markup(s.alt_text.as_deref().unwrap_or_default())
+            ));
+        }
+        SlideContent::Empty(_) => {}
+        SlideContent::Video(s) => {
+            body.push_str(&format!(
+                "<video src=\"{}\"{}{}></video>\n",
+                escape_markup(&s.video_path),
+                if s.loop_playback { " loop" } else { "" },
+                if s.autoplay { " autoplay muted" } else { "" }
+            ));
+        }
+        SlideContent::Countdown(s) => {
+            body.push_str(&format!(
+                "<p class=\"countdown\">{}:{:02}</p>\n",
+                s.target_seconds / 60,
+                s.target_seconds % 60
+            ));
+            if let Some(message) = &s.message {
+                body.push_str(&paragraph(message, None));
+            }
+        }
+    }
+    if let Some(meta_text) = content.meta_text() {
+        body.push_str(&paragraph(meta_text, Some("meta")));
+    }
+    body
+}
+
+fn paragraph(text: &str, class: Option<&str>) -> String {
+    match class {
+        Some(class) => format!("<p class=\"{}\">{}</p>\n", class, text_with_breaks(text)),
+        None => format!("<p>{}</p>\n", text_with_breaks(text)),
+    }
+}
+
+fn text_with_breaks(text: &str) -> String {
+    text.lines()
+        .map(escape_markup)
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chapter_to_html() {
+        let picture = Slide::from_content(SlideContent::SimplePicture(
+            SimplePictureSlide::new("cross.png".to_string()).with_alt_text("A cross".to_string()),
+        ));
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Tom & Jerry".to_string(), None),
+                Slide::new_content_slide(
+                    "<script>alert(\"x\")</script>\nSecond line".to_string(),
+                    None,
+                    Some("Verse 1".to_string()),
+                ),
+                picture,
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        let html = chapter_to_html(&chapter);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert_eq!(html.matches("<section").count(), 4);
+        assert!(html.contains("<h1>Tom &amp; Jerry</h1>"));
+        assert!(
+            html.contains(
+                "<p>&lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt;<br>Second line</p>"
+            )
+        );
+        assert!(html.contains("<p class=\"meta\">Verse 1</p>"));
+        assert!(html.contains("<img src=\"cross.png\" alt=\"A cross\">"));
+        assert!(html.contains("<section class=\"empty black\">"));
+        assert!(!html.contains("<script>"));
+    }
+}
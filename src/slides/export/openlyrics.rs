@@ -0,0 +1,137 @@
+use super::escape_markup;
+use crate::slides::*;
+
+/// Renders a presentation chapter as a minimal OpenLyrics 0.9 document.
+/// The first title slide (or else a non-empty linked title) becomes the song title, every
+/// content slide becomes a `<verse>` and the meta texts of the content slides become comments.
+/// Multi-language slides produce one `<verse>` per language with the same name.
+pub fn chapter_to_openlyrics<T, M>(chapter: &PresentationChapter<T, M>) -> String {
+    let title = chapter
+        .display_title()
+        .unwrap_or_else(|| "Untitled".to_string());
+
+    let mut comments: Vec<&str> = Vec::new();
+    let mut verses = String::new();
+    let mut verse_number = 0;
+    for slide in &chapter.slides {
+        match &slide.slide_content {
+            SlideContent::SingleLanguageMainContent(s) => {
+                verse_number += 1;
+                verses.push_str(&verse(verse_number, None, &s.main_text));
+            }
+            SlideContent::MultiLanguageMainContent(s) => {
+                verse_number += 1;
+                for (index, main_text) in s.main_text_list.iter().enumerate() {
+                    let language = s.language_codes.get(index).map(String::as_str);
+                    verses.push_str(&verse(verse_number, language, main_text));
+                }
+            }
+            _ => continue,
+        }
+        if let Some(meta_text) = slide.slide_content.meta_text()
+            && !comments.contains(&meta_text)
+        {
+            comments.push(meta_text);
+        }
+    }
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(
+        "<song xmlns=\"http://openlyrics.info/namespace/2009/song\" version=\"0.9\" \
+         createdIn=\"cantara-slides\">\n",
+    );
+    xml.push_str("  <properties>\n    <titles>\n");
+    xml.push_str(&format!("      <title>{}</title>\n", escape_markup(&title)));
+    xml.push_str("    </titles>\n");
+    if !comments.is_empty() {
+        xml.push_str("    <comments>\n");
+        for comment in comments {
+            xml.push_str(&format!(
+                "      <comment>{}</comment>\n",
+                escape_markup(comment)
+            ));
+        }
+        xml.push_str("    </comments>\n");
+    }
+    xml.push_str("  </properties>\n  <lyrics>\n");
+    xml.push_str(&verses);
+    xml.push_str("  </lyrics>\n</song>\n");
+    xml
+}
+
+fn verse(number: usize, language: Option<&str>, text: &str) -> String {
+    let language = language
+        .map(|code| format!(" lang=\"{}\"", escape_markup(code)))
+        .unwrap_or_default();
+    let lines = text
+        .lines()
+        .map(escape_markup)
+        .collect::<Vec<_>>()
+        .join("<br/>");
+    format!(
+        "    <verse name=\"v{}\"{}>\n      <lines>{}</lines>\n    </verse>\n",
+        number, language, lines
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chapter_to_openlyrics() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None),
+                Slide::new_content_slide(
+                    "Amazing grace\nhow sweet the sound".to_string(),
+                    None,
+                    Some("Verse 1".to_string()),
+                ),
+                Slide::new_empty_slide(true),
+                Slide::new_content_slide(
+                    "'Twas grace & love".to_string(),
+                    None,
+                    Some("Verse 2".to_string()),
+                ),
+            ],
+            LinkedEntity::Title("Song".to_string()),
+        );
+
+        let xml = chapter_to_openlyrics(&chapter);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<title>Amazing Grace</title>"));
+        assert_eq!(xml.matches("<verse ").count(), 2);
+        assert!(xml.contains(
+            "<verse name=\"v1\">\n      <lines>Amazing grace<br/>how sweet the sound</lines>"
+        ));
+        assert!(xml.contains("<lines>&#39;Twas grace &amp; love</lines>"));
+        assert!(xml.contains("<comment>Verse 1</comment>"));
+        assert!(xml.contains("<comment>Verse 2</comment>"));
+        assert!(xml.ends_with("</song>\n"));
+    }
+
+    #[test]
+    fn test_multi_language_openlyrics() {
+        let mut slide = SingleLanguageMainContentSlide {
+            main_text: "Amazing grace".to_string(),
+            ..Default::default()
+        }
+        .into_multi_language("en".to_string());
+        slide.add_language("de".to_string(), "Erstaunliche Gnade".to_string(), None);
+        let content = Slide::from_content(SlideContent::MultiLanguageMainContent(slide));
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![content],
+            LinkedEntity::Title(String::new()),
+        );
+
+        let xml = chapter_to_openlyrics(&chapter);
+
+        assert!(xml.contains("<title>Untitled</title>"));
+        assert!(!xml.contains("<comments>"));
+        assert!(xml.contains("<verse name=\"v1\" lang=\"en\">"));
+        assert!(xml.contains("<verse name=\"v1\" lang=\"de\">"));
+    }
+}
@@ -0,0 +1,93 @@
+use super::escape_markup;
+use super::html::slide_body;
+use crate::slides::*;
+
+/// Renders the slides of a presentation chapter as reveal.js `<section>` elements, meant to be
+/// inserted into the `<div class="slides">` of a reveal.js template.
+/// Transitions, backgrounds and durations of the slides are passed on as `data-` attributes,
+/// the presenter notes become reveal.js speaker notes.
+pub fn chapter_to_revealjs<T, M>(chapter: &PresentationChapter<T, M>) -> String {
+    let mut html = String::new();
+    for slide in &chapter.slides {
+        html.push_str("<section");
+        for (name, value) in slide_attributes(slide) {
+            html.push_str(&format!(" {}=\"{}\"", name, escape_markup(&value)));
+        }
+        html.push_str(">\n");
+        html.push_str(&slide_body(&slide.slide_content));
+        if let Some(notes) = &slide.notes {
+            html.push_str(&format!(
+                "<aside class=\"notes\">{}</aside>\n",
+                escape_markup(notes)
+            ));
+        }
+        html.push_str("</section>\n");
+    }
+    html
+}
+
+fn slide_attributes<M>(slide: &Slide<M>) -> Vec<(&'static str, String)> {
+    let mut attributes = Vec::new();
+    if let Some(transition) = &slide.transition {
+        let name = match transition {
+            Transition::Cut => "none",
+            Transition::Fade { .. } => "fade",
+            Transition::Slide { .. } => "slide",
+        };
+        attributes.push(("data-transition", name.to_string()));
+    }
+    match &slide.background {
+        Some(Background::Color { rgba: [r, g, b, a] }) => attributes.push((
+            "data-background-color",
+            format!("rgba({}, {}, {}, {:.3})", r, g, b, *a as f32 / 255.0),
+        )),
+        Some(Background::Image { path }) => {
+            attributes.push(("data-background-image", path.clone()))
+        }
+        None => {
+            if let SlideContent::Empty(s) = &slide.slide_content {
+                let color = if s.black_background { "#000" } else { "#fff" };
+                attributes.push(("data-background-color", color.to_string()));
+            }
+        }
+    }
+    if let Some(duration_millis) = slide.duration_millis {
+        attributes.push(("data-autoslide", duration_millis.to_string()));
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chapter_to_revealjs() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide("Amazing Grace".to_string(), None)
+                    .with_transition(Transition::Fade { millis: 500 }),
+                Slide::new_content_slide("Amazing grace".to_string(), None, None)
+                    .with_background(Background::Color {
+                        rgba: [255, 0, 0, 255],
+                    })
+                    .with_duration(8000)
+                    .with_notes("Slowly".to_string()),
+                Slide::new_empty_slide(true).with_transition(Transition::Cut),
+            ],
+            LinkedEntity::Title("Amazing Grace".to_string()),
+        );
+
+        let html = chapter_to_revealjs(&chapter);
+
+        assert_eq!(html.matches("<section").count(), 3);
+        assert_eq!(html.matches("</section>").count(), 3);
+        assert!(html.contains("<section data-transition=\"fade\">\n<h1>Amazing Grace</h1>"));
+        assert!(html.contains(
+            "<section data-background-color=\"rgba(255, 0, 0, 1.000)\" data-autoslide=\"8000\">"
+        ));
+        assert!(html.contains("<aside class=\"notes\">Slowly</aside>"));
+        assert!(html.contains("<section data-transition=\"none\" data-background-color=\"#000\">"));
+        assert!(!html.contains("<html"));
+    }
+}
@@ -0,0 +1,504 @@
+use crate::slides::*;
+
+/// Creates a chapter from plain text in which blank lines separate the verses.
+/// Every verse becomes a single-language content slide with its surrounding whitespace trimmed.
+/// If a title is given, a title slide is prepended and the chapter is linked to the title.
+pub fn from_plain_text(input: &str, title: Option<String>) -> PresentationChapter<String, String> {
+    let mut slides = Vec::new();
+    if let Some(title) = &title {
+        slides.push(Slide::new_title_slide(title.clone(), None));
+    }
+    slides.extend(
+        text_blocks(input)
+            .into_iter()
+            .map(|block| Slide::new_content_slide(block, None, None)),
+    );
+    PresentationChapter::new(
+        slides,
+        LinkedEntity::Title(title.map(|t| t.trim().to_string()).unwrap_or_default()),
+    )
+}
+
+/// Splits the input on blank lines into non-empty blocks of lines
+fn text_blocks(input: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(current.join("\n").trim().to_string());
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current.join("\n").trim().to_string());
+    }
+    blocks
+}
+
+/// Creates a chapter from a song in the ChordPro format.
+/// The `title` directive becomes a title slide (with the `subtitle` as its meta text), every
+/// verse, chorus or bridge environment becomes a content slide with the environment label as
+/// its meta text. Lyrics outside of environments are split into slides on blank lines.
+/// Chords (`[G]`) are removed from the lyrics, comments and unknown directives are ignored.
+pub fn from_chordpro(input: &str) -> Result<PresentationChapter<String, String>, ChordProError> {
+    let mut title: Option<String> = None;
+    let mut subtitle: Option<String> = None;
+    let mut slides = Vec::new();
+    // The label of the open environment (if any) and the lyrics collected so far
+    let mut section: Option<Option<String>> = None;
+    let mut lines: Vec<String> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(directive) = trimmed.strip_prefix('{') {
+            let directive = directive
+                .strip_suffix('}')
+                .ok_or(ChordProError::UnclosedDirective { line: line_number })?;
+            let (name, value) = match directive.split_once(':') {
+                Some((name, value)) => (name.trim(), Some(value.trim().to_string())),
+                None => (directive.trim(), None),
+            };
+            match name.to_lowercase().as_str() {
+                "title" | "t" => title = value,
+                "subtitle" | "st" => subtitle = value,
+                "start_of_verse" | "sov" | "start_of_chorus" | "soc" | "start_of_bridge"
+                | "sob" => {
+                    if section.is_some() {
+                        return Err(ChordProError::NestedSection { line: line_number });
+                    }
+                    flush_lyrics(&mut slides, &mut lines, None);
+                    section = Some(value.filter(|v| !v.is_empty()));
+                }
+                "end_of_verse" | "eov" | "end_of_chorus" | "eoc" | "end_of_bridge" | "eob" => {
+                    let label = section
+                        .take()
+                        .ok_or(ChordProError::UnexpectedSectionEnd { line: line_number })?;
+                    flush_lyrics(&mut slides, &mut lines, label);
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let lyrics =
+            strip_chords(line).ok_or(ChordProError::UnclosedChord { line: line_number })?;
+        if lyrics.trim().is_empty() {
+            if section.is_none() {
+                flush_lyrics(&mut slides, &mut lines, None);
+            }
+        } else {
+            lines.push(lyrics.trim_end().to_string());
+        }
+    }
+
+    if section.is_some() {
+        return Err(ChordProError::UnterminatedSection);
+    }
+    flush_lyrics(&mut slides, &mut lines, None);
+
+    if let Some(title) = &title {
+        slides.insert(0, Slide::new_title_slide(title.clone(), subtitle));
+    }
+    Ok(PresentationChapter::new(
+        slides,
+        LinkedEntity::Title(title.unwrap_or_default()),
+    ))
+}
+
+/// Turns the collected lyrics into a content slide, if there are any
+fn flush_lyrics(
+    slides: &mut Vec<Slide<String>>,
+    lines: &mut Vec<String>,
+    meta_text: Option<String>,
+) {
+    if !lines.is_empty() {
+        slides.push(Slide::new_content_slide(lines.join("\n"), None, meta_text));
+        lines.clear();
+    }
+}
+
+/// Removes the chords from a line of lyrics, returns `None` if a chord is not closed
+fn strip_chords(line: &str) -> Option<String> {
+    let mut lyrics = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find('[') {
+        lyrics.push_str(&rest[..start]);
+        let end = rest[start..].find(']')?;
+        rest = &rest[start + end + 1..];
+    }
+    lyrics.push_str(rest);
+    Some(lyrics)
+}
+
+/// Creates a chapter from an OpenLyrics document.
+/// The first `<title>` becomes a title slide and every `<verse>` with `<lines>` becomes a content
+/// slide whose main text keeps the line breaks (`<br/>`) of the verse. Text in CDATA sections is
+/// read like any other text, chords and comments within the lyrics are dropped.
+#[cfg(feature = "openlyrics")]
+pub fn from_openlyrics(xml: &str) -> Result<PresentationChapter<String, String>, OpenLyricsError> {
+    use quick_xml::Reader;
+    use quick_xml::events::Event;
+
+    let malformed = |e: &dyn std::fmt::Display| OpenLyricsError::MalformedXml(e.to_string());
+    let mut reader = Reader::from_str(xml);
+    // The names of the open elements
+    let mut path: Vec<String> = Vec::new();
+    let mut title: Option<String> = None;
+    let mut title_text = String::new();
+    let mut slides = Vec::new();
+    let mut verse_lines: Vec<String> = Vec::new();
+    let in_element = |path: &[String], name: &str| path.iter().any(|p| p == name);
+
+    loop {
+        let event = reader.read_event().map_err(|e| malformed(&e))?;
+        match event {
+            Event::Start(element) => {
+                let name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+                if path.is_empty() && name != "song" {
+                    return Err(OpenLyricsError::MissingSong);
+                }
+                if name == "lines" {
+                    verse_lines.push(String::new());
+                }
+                path.push(name);
+            }
+            Event::Empty(element) => {
+                if path.is_empty() {
+                    return Err(OpenLyricsError::MissingSong);
+                }
+                if element.local_name().as_ref() == b"br" && in_element(&path, "lines") {
+                    verse_lines.push(String::new());
+                }
+            }
+            Event::End(_) => {
+                let name = path.pop().unwrap_or_default();
+                if name == "title" && in_element(&path, "titles") && title.is_none() {
+                    title = Some(title_text.trim().to_string());
+                }
+                // A verse without any <lines> has no lyrics to show
+                if name == "verse" && !verse_lines.is_empty() {
+                    let lines: Vec<&str> = verse_lines.iter().map(|l| l.trim()).collect();
+                    slides.push(Slide::new_content_slide(lines.join("\n"), None, None));
+                    verse_lines.clear();
+                }
+            }
+            Event::Text(_) | Event::CData(_) => {
+                let text = match event {
+                    Event::Text(text) => text.unescape().map_err(|e| malformed(&e))?,
+                    Event::CData(data) => data.decode().map_err(|e| malformed(&e))?,
+                    _ => unreachable!(),
+                };
+                let in_title =
+                    path.last().is_some_and(|p| p == "title") && in_element(&path, "titles");
+                let in_lyrics = in_element(&path, "lines") && !in_element(&path, "comment");
+                if in_title && title.is_none() {
+                    push_markup_text(&mut title_text, &text);
+                } else if in_lyrics && let Some(line) = verse_lines.last_mut() {
+                    push_markup_text(line, &text);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if !path.is_empty() {
+        return Err(OpenLyricsError::MalformedXml(format!(
+            "the element <{}> is not closed",
+            path.last().unwrap()
+        )));
+    }
+    if title.is_none() && slides.is_empty() {
+        return Err(OpenLyricsError::MissingSong);
+    }
+
+    if let Some(title) = &title {
+        slides.insert(0, Slide::new_title_slide(title.clone(), None));
+    }
+    Ok(PresentationChapter::new(
+        slides,
+        LinkedEntity::Title(title.unwrap_or_default()),
+    ))
+}
+
+/// Appends text from within XML markup, in which whitespace is not significant
+#[cfg(feature = "openlyrics")]
+fn push_markup_text(line: &mut String, text: &str) {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if text.starts_with(char::is_whitespace) {
+        line.push(' ');
+    }
+    line.push_str(&words.join(" "));
+    if text.ends_with(char::is_whitespace) && !words.is_empty() {
+        line.push(' ');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AMAZING_GRACE: &str = "
+  Amazing grace, how sweet the sound
+That saved a wretch like me
+
+\t
+'Twas grace that taught my heart to fear
+And grace my fears relieved  \r
+\r
+\r
+Through many dangers, toils and snares
+";
+
+    #[test]
+    fn test_from_plain_text() {
+        let chapter = from_plain_text(AMAZING_GRACE, None);
+
+        assert_eq!(chapter.len(), 3);
+        assert_eq!(
+            chapter[0].slide_content,
+            SlideContent::SingleLanguageMainContent(SingleLanguageMainContentSlide {
+                main_text: "Amazing grace, how sweet the sound\nThat saved a wretch like me"
+                    .to_string(),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            chapter[1].slide_content.to_string(),
+            "'Twas grace that taught my heart to fear\nAnd grace my fears relieved"
+        );
+        assert_eq!(
+            chapter[2].slide_content.to_string(),
+            "Through many dangers, toils and snares"
+        );
+        assert_eq!(chapter.linked_entity, LinkedEntity::Title(String::new()));
+    }
+
+    #[test]
+    fn test_from_plain_text_with_title() {
+        let chapter = from_plain_text(AMAZING_GRACE, Some("Amazing Grace".to_string()));
+
+        assert_eq!(chapter.len(), 4);
+        assert!(chapter[0].is_title());
+        assert_eq!(chapter[0].slide_content.to_string(), "Amazing Grace");
+        assert!(
+            chapter
+                .iter()
+                .skip(1)
+                .all(|slide| slide.kind() == SlideContentKind::SingleLanguageMainContent)
+        );
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Amazing Grace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_plain_text_without_verses() {
+        assert!(from_plain_text("  \n\n \t\n", None).is_empty());
+    }
+
+    const AMAZING_GRACE_CHORDPRO: &str = "{title: Amazing Grace}
+{subtitle: John Newton}
+{key: G}
+# Traditional
+
+{start_of_verse: Verse 1}
+A[G]mazing [G7]grace, how [C]sweet the [G]sound
+That [G]saved a wretch like [D]me
+{end_of_verse}
+
+{soc}
+[G]Praise God, [C]praise God
+{eoc}
+";
+
+    #[test]
+    fn test_from_chordpro() {
+        let chapter = from_chordpro(AMAZING_GRACE_CHORDPRO).unwrap();
+
+        assert_eq!(chapter.len(), 3);
+        assert_eq!(
+            chapter[0].slide_content,
+            SlideContent::Title(TitleSlide {
+                title_text: "Amazing Grace".to_string(),
+                meta_text: Some("John Newton".to_string()),
+            })
+        );
+        assert_eq!(
+            chapter[1].slide_content,
+            SlideContent::SingleLanguageMainContent(SingleLanguageMainContentSlide {
+                main_text: "Amazing grace, how sweet the sound\nThat saved a wretch like me"
+                    .to_string(),
+                meta_text: Some("Verse 1".to_string()),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            chapter[2].slide_content.to_string(),
+            "Praise God, praise God"
+        );
+        assert_eq!(chapter[2].slide_content.meta_text(), None);
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Amazing Grace".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_chordpro_without_environments() {
+        let chapter = from_chordpro("[G]First verse\n\n[C]Second verse\n").unwrap();
+
+        assert_eq!(slide_texts(&chapter), vec!["First verse", "Second verse"]);
+        assert_eq!(chapter.linked_entity, LinkedEntity::Title(String::new()));
+    }
+
+    #[test]
+    fn test_from_chordpro_errors() {
+        assert_eq!(
+            from_chordpro("{title: Amazing Grace").unwrap_err(),
+            ChordProError::UnclosedDirective { line: 1 }
+        );
+        assert_eq!(
+            from_chordpro("{sov}\nA[G mazing\n{eov}").unwrap_err(),
+            ChordProError::UnclosedChord { line: 2 }
+        );
+        assert_eq!(
+            from_chordpro("{sov}\n{soc}").unwrap_err(),
+            ChordProError::NestedSection { line: 2 }
+        );
+        assert_eq!(
+            from_chordpro("Amazing grace\n{eoc}").unwrap_err(),
+            ChordProError::UnexpectedSectionEnd { line: 2 }
+        );
+        assert_eq!(
+            from_chordpro("{sov}\nAmazing grace").unwrap_err(),
+            ChordProError::UnterminatedSection
+        );
+    }
+
+    fn slide_texts(chapter: &PresentationChapter<String, String>) -> Vec<String> {
+        chapter
+            .iter()
+            .map(|slide| slide.slide_content.to_string())
+            .collect()
+    }
+
+    #[cfg(feature = "openlyrics")]
+    const AMAZING_GRACE_OPENLYRICS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<song xmlns="http://openlyrics.info/namespace/2009/song" version="0.9">
+  <properties>
+    <titles>
+      <title>Amazing Grace</title>
+      <title lang="de">Erstaunliche Gnade</title>
+    </titles>
+  </properties>
+  <lyrics>
+    <verse name="v1">
+      <lines>
+        <chord name="G"/>Amazing grace, how sweet the sound<br/>
+        That saved a wretch like me
+      </lines>
+    </verse>
+    <verse name="c">
+      <lines><comment>slowly</comment>Praise God &amp; sing</lines>
+    </verse>
+  </lyrics>
+</song>
+"#;
+
+    #[cfg(feature = "openlyrics")]
+    #[test]
+    fn test_from_openlyrics() {
+        let chapter = from_openlyrics(AMAZING_GRACE_OPENLYRICS).unwrap();
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec![
+                "Amazing Grace",
+                "Amazing grace, how sweet the sound\nThat saved a wretch like me",
+                "Praise God & sing",
+            ]
+        );
+        assert!(chapter[0].is_title());
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title("Amazing Grace".to_string())
+        );
+    }
+
+    #[cfg(feature = "openlyrics")]
+    #[test]
+    fn test_openlyrics_round_trip() {
+        let chapter = from_chordpro(AMAZING_GRACE_CHORDPRO).unwrap();
+
+        let imported =
+            from_openlyrics(&export::openlyrics::chapter_to_openlyrics(&chapter)).unwrap();
+
+        assert_eq!(slide_texts(&imported), slide_texts(&chapter));
+    }
+
+    #[cfg(feature = "openlyrics")]
+    #[test]
+    fn test_from_openlyrics_with_cdata() {
+        let chapter = from_openlyrics(
+            "<song><properties><titles><title><![CDATA[Amazing & Grace]]></title></titles></properties>\
+             <lyrics><verse name=\"v1\"><lines><![CDATA[Amazing grace]]><br/>how <![CDATA[sweet]]></lines></verse></lyrics></song>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec!["Amazing & Grace", "Amazing grace\nhow sweet"]
+        );
+    }
+
+    #[cfg(feature = "openlyrics")]
+    #[test]
+    fn test_from_openlyrics_skips_verses_without_lines() {
+        let chapter = from_openlyrics(
+            "<song><properties><titles><title>Amazing Grace</title></titles></properties>\
+             <lyrics><verse name=\"v1\"/><verse name=\"v2\"><comment>Instrumental</comment></verse>\
+             <verse name=\"v3\"><lines>Amazing grace</lines></verse></lyrics></song>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            slide_texts(&chapter),
+            vec!["Amazing Grace", "Amazing grace"]
+        );
+    }
+
+    #[cfg(feature = "openlyrics")]
+    #[test]
+    fn test_from_malformed_openlyrics() {
+        assert!(matches!(
+            from_openlyrics("<song><lyrics><verse><lines>Amazing</verse></lyrics></song>"),
+            Err(OpenLyricsError::MalformedXml(_))
+        ));
+        assert!(matches!(
+            from_openlyrics("<song><lyrics>"),
+            Err(OpenLyricsError::MalformedXml(_))
+        ));
+        assert!(matches!(
+            from_openlyrics("<song><title>&unknown;</title></song>"),
+            Err(OpenLyricsError::MalformedXml(_))
+        ));
+        assert_eq!(
+            from_openlyrics("<html><body/></html>").unwrap_err(),
+            OpenLyricsError::MissingSong
+        );
+        assert_eq!(
+            from_openlyrics("").unwrap_err(),
+            OpenLyricsError::MissingSong
+        );
+    }
+}
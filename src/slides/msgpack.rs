@@ -0,0 +1,73 @@
+use super::*;
+
+/// Serializes a chapter to MessagePack. Structs are written as maps with their field names,
+/// so the data can be read by other MessagePack implementations and by later versions.
+pub fn to_msgpack<T, M>(
+    chapter: &PresentationChapter<T, M>,
+) -> Result<Vec<u8>, rmp_serde::encode::Error>
+where
+    T: Serialize,
+    M: Serialize,
+{
+    rmp_serde::to_vec_named(chapter)
+}
+
+/// Deserializes a chapter from MessagePack
+pub fn from_msgpack<T, M>(
+    bytes: &[u8],
+) -> Result<PresentationChapter<T, M>, rmp_serde::decode::Error>
+where
+    T: for<'de> Deserialize<'de>,
+    M: for<'de> Deserialize<'de>,
+{
+    rmp_serde::from_slice(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+    struct SongSource {
+        path: String,
+        line: u32,
+    }
+
+    #[test]
+    fn test_msgpack_round_trip() {
+        let entities = [
+            LinkedEntity::Source(SongSource {
+                path: "amazing-grace.song".to_string(),
+                line: 3,
+            }),
+            LinkedEntity::Title("Amazing Grace".to_string()),
+            LinkedEntity::Media(42),
+        ];
+
+        for linked_entity in entities {
+            let chapter = PresentationChapter::<SongSource, u32>::new(
+                vec![
+                    Slide::new_title_slide("Amazing Grace".to_string(), None).with_media(7),
+                    Slide::new_content_slide(
+                        "Amazing grace".to_string(),
+                        None,
+                        Some("Verse 1".to_string()),
+                    )
+                    .with_transition(Transition::Fade { millis: 500 }),
+                    Slide::new_empty_slide(true),
+                ],
+                linked_entity,
+            );
+
+            let bytes = to_msgpack(&chapter).unwrap();
+            let deserialized: PresentationChapter<SongSource, u32> = from_msgpack(&bytes).unwrap();
+
+            assert_eq!(deserialized, chapter);
+        }
+    }
+
+    #[test]
+    fn test_invalid_msgpack() {
+        assert!(from_msgpack::<String, String>(&[0xc1]).is_err());
+    }
+}
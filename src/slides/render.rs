@@ -0,0 +1,159 @@
+use super::*;
+
+/// The purpose of a text on a slide, renderers usually style each role differently
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TextRole {
+    Title,
+    Main,
+    Spoiler,
+    Meta,
+}
+
+/// A single backend-agnostic drawing instruction
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RenderCommand {
+    /// Clears the slide with a black or non-black (usually white) background
+    Clear { black: bool },
+
+    /// Draws a text with the given role
+    Text { text: String, role: TextRole },
+
+    /// Draws the picture at the given path
+    Image { path: String },
+
+    /// Plays the video at the given path
+    Video {
+        path: String,
+        loop_playback: bool,
+        autoplay: bool,
+    },
+
+    /// Shows a countdown over the given number of seconds
+    Countdown { target_seconds: u32 },
+}
+
+/// Content which can be turned into a sequence of render commands, in drawing order
+pub trait Renderable {
+    fn render(&self) -> Vec<RenderCommand>;
+}
+
+impl Renderable for SlideContent {
+    fn render(&self) -> Vec<RenderCommand> {
+        let text = |text: &str, role: TextRole| RenderCommand::Text {
+            text: text.to_string(),
+            role,
+        };
+        let mut commands = Vec::new();
+        match self {
+            SlideContent::SingleLanguageMainContent(s) => {
+                commands.push(text(&s.main_text, TextRole::Main));
+                if let Some(spoiler_text) = &s.spoiler_text {
+                    commands.push(text(spoiler_text, TextRole::Spoiler));
+                }
+            }
+            SlideContent::Title(s) => commands.push(text(&s.title_text, TextRole::Title)),
+            SlideContent::MultiLanguageMainContent(s) => {
+                for main_text in &s.main_text_list {
+                    commands.push(text(main_text, TextRole::Main));
+                }
+                for spoiler_text in s.spoiler_text_vector.iter().filter(|t| !t.is_empty()) {
+                    commands.push(text(spoiler_text, TextRole::Spoiler));
+                }
+            }
+            SlideContent::SimplePicture(s) => commands.push(RenderCommand::Image {
+                path: s.picture_path.clone(),
+            }),
+            SlideContent::Empty(s) => commands.push(RenderCommand::Clear {
+                black: s.black_background,
+            }),
+            SlideContent::Video(s) => commands.push(RenderCommand::Video {
+                path: s.video_path.clone(),
+                loop_playback: s.loop_playback,
+                autoplay: s.autoplay,
+            }),
+            SlideContent::Countdown(s) => {
+                if s.black_background {
+                    commands.push(RenderCommand::Clear { black: true });
+                }
+                commands.push(RenderCommand::Countdown {
+                    target_seconds: s.target_seconds,
+                });
+                if let Some(message) = &s.message {
+                    commands.push(text(message, TextRole::Main));
+                }
+            }
+        }
+        if let Some(meta_text) = self.meta_text() {
+            commands.push(text(meta_text, TextRole::Meta));
+        }
+        commands
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_content_slide() {
+        let slide = Slide::<String>::new_content_slide(
+            "Amazing grace".to_string(),
+            Some("How sweet the sound".to_string()),
+            Some("Verse 1".to_string()),
+        );
+
+        assert_eq!(
+            slide.slide_content.render(),
+            vec![
+                RenderCommand::Text {
+                    text: "Amazing grace".to_string(),
+                    role: TextRole::Main,
+                },
+                RenderCommand::Text {
+                    text: "How sweet the sound".to_string(),
+                    role: TextRole::Spoiler,
+                },
+                RenderCommand::Text {
+                    text: "Verse 1".to_string(),
+                    role: TextRole::Meta,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_other_slides() {
+        assert_eq!(
+            Slide::<String>::new_title_slide("Amazing Grace".to_string(), None)
+                .slide_content
+                .render(),
+            vec![RenderCommand::Text {
+                text: "Amazing Grace".to_string(),
+                role: TextRole::Title,
+            }]
+        );
+        assert_eq!(
+            Slide::<String>::new_empty_slide(false)
+                .slide_content
+                .render(),
+            vec![RenderCommand::Clear { black: false }]
+        );
+        assert_eq!(
+            SlideContent::SimplePicture(SimplePictureSlide::new("cross.png".to_string())).render(),
+            vec![RenderCommand::Image {
+                path: "cross.png".to_string()
+            }]
+        );
+        assert_eq!(
+            Slide::<String>::new_countdown_slide(300, None)
+                .slide_content
+                .render(),
+            vec![
+                RenderCommand::Clear { black: true },
+                RenderCommand::Countdown {
+                    target_seconds: 300
+                },
+            ]
+        );
+    }
+}
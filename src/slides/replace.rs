@@ -0,0 +1,80 @@
+use super::*;
+use regex::Regex;
+
+impl<T, M> PresentationChapter<T, M>
+where
+    T: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    /// Replaces every match of the regular expression in the visible text of all slides
+    /// (see [`SlideContent::all_text`]) and returns the number of replacements.
+    /// The replacement may refer to capture groups, e.g. `$1`, like in [`Regex::replace_all`].
+    pub fn replace_regex(
+        &mut self,
+        pattern: &str,
+        replacement: &str,
+    ) -> Result<usize, regex::Error> {
+        let regex = Regex::new(pattern)?;
+        let mut replacements = 0;
+        for slide in &mut self.slides {
+            for text in slide.slide_content.text_fields_mut() {
+                let matches = regex.find_iter(text).count();
+                if matches > 0 {
+                    *text = regex.replace_all(text, replacement).into_owned();
+                    replacements += matches;
+                }
+            }
+        }
+        Ok(replacements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_regex() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Praise the LORD".to_string(),
+                    Some("Psalm 150".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Praise the Lord in his sanctuary\nPraise the lord".to_string(),
+                    Some("LORD of hosts".to_string()),
+                    None,
+                ),
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Title("Psalm 150".to_string()),
+        );
+
+        let replacements = chapter.replace_regex(r"(?i)\blord\b", "Lord").unwrap();
+
+        assert_eq!(replacements, 4);
+        assert_eq!(chapter[0].to_string(), "Praise the Lord");
+        assert_eq!(
+            chapter[1].slide_content.all_text(),
+            vec![
+                "Praise the Lord in his sanctuary\nPraise the Lord",
+                "Lord of hosts"
+            ]
+        );
+        assert_eq!(chapter.replace_regex(r"Psalm (\d+)", "Ps. $1").unwrap(), 1);
+        assert_eq!(chapter[0].slide_content.meta_text(), Some("Ps. 150"));
+        assert_eq!(chapter.replace_regex("Hallelujah", "Amen").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_replace_regex_invalid_pattern() {
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![Slide::new_title_slide("Praise the LORD".to_string(), None)],
+            LinkedEntity::Title("Psalm 150".to_string()),
+        );
+
+        assert!(chapter.replace_regex("(unclosed", "x").is_err());
+        assert_eq!(chapter[0].to_string(), "Praise the LORD");
+    }
+}
@@ -0,0 +1,124 @@
+use super::*;
+use schemars::JsonSchema;
+use schemars::schema::RootSchema;
+
+/// Generates the JSON Schema of a presentation chapter with the given entity and media types
+pub fn presentation_chapter_schema<T, M>() -> RootSchema
+where
+    T: JsonSchema,
+    M: JsonSchema,
+{
+    schemars::schema_for!(PresentationChapter<T, M>)
+}
+
+/// Generates the JSON Schema of a whole presentation with the given entity and media types
+pub fn presentation_schema<T, M>() -> RootSchema
+where
+    T: JsonSchema,
+    M: JsonSchema,
+{
+    schemars::schema_for!(Presentation<T, M>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition_properties(schema: &serde_json::Value, definition: &str) -> Vec<String> {
+        schema["definitions"][definition]["properties"]
+            .as_object()
+            .unwrap_or_else(|| panic!("Missing definition {}", definition))
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the properties of a slide content type, which the internally tagged
+    /// representation inlines into the variants of the `SlideContent` definition
+    #[cfg(feature = "tagged-content")]
+    fn content_properties(schema: &serde_json::Value, definition: &str) -> Vec<String> {
+        let variant = definition.strip_suffix("Slide").unwrap();
+        schema["definitions"]["SlideContent"]["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|schema| schema["properties"]["type"]["enum"][0] == variant)
+            .unwrap_or_else(|| panic!("Missing variant {}", variant))["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    #[cfg(not(feature = "tagged-content"))]
+    fn content_properties(schema: &serde_json::Value, definition: &str) -> Vec<String> {
+        definition_properties(schema, definition)
+    }
+
+    #[test]
+    fn test_presentation_chapter_schema() {
+        let schema = serde_json::to_value(presentation_chapter_schema::<String, String>()).unwrap();
+
+        let chapter_properties: Vec<&str> = schema["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert!(chapter_properties.contains(&"slides"));
+        assert!(chapter_properties.contains(&"linked_entity"));
+        assert!(chapter_properties.contains(&"schema_version"));
+
+        let expected = [
+            ("SingleLanguageMainContentSlide", "main_text"),
+            ("SingleLanguageMainContentSlide", "spoiler_text"),
+            ("TitleSlide", "title_text"),
+            ("MultiLanguageMainContentSlide", "main_text_list"),
+            ("MultiLanguageMainContentSlide", "language_codes"),
+            ("SimplePictureSlide", "picture_path"),
+            ("EmptySlide", "black_background"),
+            ("VideoSlide", "video_path"),
+            ("CountdownSlide", "target_seconds"),
+        ];
+        for (definition, property) in expected {
+            assert!(
+                content_properties(&schema, definition).contains(&property.to_string()),
+                "{} is missing the property {}",
+                definition,
+                property
+            );
+        }
+        assert!(
+            definition_properties(&schema, "Slide_for_String")
+                .contains(&"slide_content".to_string())
+        );
+    }
+
+    #[cfg(feature = "tagged-content")]
+    #[test]
+    fn test_tagged_content_schema() {
+        let schema = serde_json::to_value(presentation_chapter_schema::<String, String>()).unwrap();
+
+        let variants = schema["definitions"]["SlideContent"]["oneOf"]
+            .as_array()
+            .unwrap();
+        assert_eq!(variants.len(), 7);
+        for variant in variants {
+            assert!(
+                variant["required"]
+                    .as_array()
+                    .unwrap()
+                    .contains(&serde_json::json!("type"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_presentation_schema() {
+        let schema = serde_json::to_value(presentation_schema::<String, String>()).unwrap();
+
+        assert!(schema["properties"]["chapters"].is_object());
+        assert!(schema["properties"]["title"].is_object());
+    }
+}
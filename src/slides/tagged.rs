@@ -0,0 +1,229 @@
+use super::*;
+
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{
+    DeserializeSeed, Deserializer, EnumAccess, Error, IgnoredAny, MapAccess, VariantAccess, Visitor,
+};
+
+const VARIANTS: &[&str] = &[
+    "SingleLanguageMainContent",
+    "Title",
+    "MultiLanguageMainContent",
+    "SimplePicture",
+    "Empty",
+    "Video",
+    "Countdown",
+];
+
+/// Reads both the internally tagged form (`{"type": "Title", ...}`) and the legacy externally
+/// tagged form (`{"Title": {...}}`, or `!Title {...}` in YAML). As long as the `type` field comes
+/// first, the content is deserialized without buffering, so unknown fields can still be detected.
+impl<'de> Deserialize<'de> for SlideContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Self-describing formats may present the legacy form as an enum (YAML tags), the
+        // binary formats cannot be asked for whatever comes next
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SlideContentVisitor)
+        } else {
+            deserializer.deserialize_map(SlideContentVisitor)
+        }
+    }
+}
+
+struct SlideContentVisitor;
+
+impl<'de> Visitor<'de> for SlideContentVisitor {
+    type Value = SlideContent;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("slide content")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let key: String = map
+            .next_key()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        if key == "type" {
+            let variant: String = map.next_value()?;
+            return content_of_variant(&variant, MapAccessDeserializer::new(map));
+        }
+
+        if !VARIANTS.contains(&key.as_str()) {
+            return content_of_buffered_map(key, map);
+        }
+
+        let content = map.next_value_seed(VariantSeed(key))?;
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(2, &"a single variant"));
+        }
+        Ok(content)
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, content): (String, _) = data.variant()?;
+        content.newtype_variant_seed(VariantSeed(variant))
+    }
+}
+
+/// Falls back to buffering the fields when the `type` field does not come first, e.g. for maps
+/// that were sorted by key. Unknown fields are not reported to the caller in this case.
+/// The fields are buffered as JSON values whatever the input format is, which is enough for the
+/// plain strings, numbers and booleans of the slide contents (but not for YAML tags among them).
+fn content_of_buffered_map<'de, A>(first_key: String, mut map: A) -> Result<SlideContent, A::Error>
+where
+    A: MapAccess<'de>,
+{
+    let mut fields = serde_json::Map::new();
+    fields.insert(first_key, map.next_value()?);
+    while let Some((key, value)) = map.next_entry::<String, serde_json::Value>()? {
+        fields.insert(key, value);
+    }
+    let variant = match fields.remove("type") {
+        Some(serde_json::Value::String(variant)) => variant,
+        Some(_) => return Err(A::Error::custom("the `type` field must be a string")),
+        None => return Err(A::Error::missing_field("type")),
+    };
+    content_of_variant(&variant, serde_json::Value::Object(fields)).map_err(A::Error::custom)
+}
+
+struct VariantSeed(String);
+
+impl<'de> DeserializeSeed<'de> for VariantSeed {
+    type Value = SlideContent;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        content_of_variant(&self.0, deserializer)
+    }
+}
+
+fn content_of_variant<'de, D>(variant: &str, deserializer: D) -> Result<SlideContent, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match variant {
+        "SingleLanguageMainContent" => {
+            Deserialize::deserialize(deserializer).map(SlideContent::SingleLanguageMainContent)
+        }
+        "Title" => Deserialize::deserialize(deserializer).map(SlideContent::Title),
+        "MultiLanguageMainContent" => {
+            Deserialize::deserialize(deserializer).map(SlideContent::MultiLanguageMainContent)
+        }
+        "SimplePicture" => Deserialize::deserialize(deserializer).map(SlideContent::SimplePicture),
+        "Empty" => Deserialize::deserialize(deserializer).map(SlideContent::Empty),
+        "Video" => Deserialize::deserialize(deserializer).map(SlideContent::Video),
+        "Countdown" => Deserialize::deserialize(deserializer).map(SlideContent::Countdown),
+        other => Err(D::Error::unknown_variant(other, VARIANTS)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tagged_serialization() {
+        let slide = Slide::<String>::new_title_slide("Amazing Grace".to_string(), None);
+
+        let json = serde_json::to_value(&slide).unwrap();
+
+        assert_eq!(
+            json["slide_content"],
+            serde_json::json!({"type": "Title", "title_text": "Amazing Grace"})
+        );
+        assert_eq!(
+            serde_json::from_value::<Slide<String>>(json).unwrap(),
+            slide
+        );
+    }
+
+    #[test]
+    fn test_read_legacy_content() {
+        let json = r#"{
+            "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+            "slide_content": {"SingleLanguageMainContent": {
+                "main_text": "Amazing grace",
+                "spoiler_text": null,
+                "meta_text": "Verse 1"
+            }}
+        }"#;
+
+        let slide: Slide<String> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            slide.slide_content,
+            Slide::<String>::new_content_slide(
+                "Amazing grace".to_string(),
+                None,
+                Some("Verse 1".to_string())
+            )
+            .slide_content
+        );
+    }
+
+    #[test]
+    fn test_read_tagged_content() {
+        let json = r#"{"type": "Empty", "black_background": true}"#;
+
+        let content: SlideContent = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            content,
+            SlideContent::Empty(EmptySlide {
+                black_background: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_content_is_rejected() {
+        assert!(serde_json::from_str::<SlideContent>(r#"{"type": "Unknown"}"#).is_err());
+        assert!(serde_json::from_str::<SlideContent>(r#"{"Title": 1}"#).is_err());
+    }
+
+    #[test]
+    fn test_strict_mode_detects_unknown_tagged_fields() {
+        let json = r#"{
+            "slides": [{
+                "uuid": "01936b2e-1e2a-7cc0-8000-000000000000",
+                "slide_content": {"type": "Title", "title_text": "Amazing Grace", "titel": "x"}
+            }],
+            "linked_entity": {"Title": "Amazing Grace"}
+        }"#;
+
+        assert!(PresentationChapter::<String, String>::from_json_str(json).is_ok());
+        assert!(PresentationChapter::<String, String>::from_json_str_strict(json).is_err());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_read_legacy_yaml_content() {
+        let expected = SlideContent::Title(TitleSlide {
+            title_text: "Amazing Grace".to_string(),
+            meta_text: None,
+        });
+
+        let tagged: SlideContent =
+            serde_yaml::from_str("!Title\ntitle_text: Amazing Grace\n").unwrap();
+        let nested: SlideContent =
+            serde_yaml::from_str("Title:\n  title_text: Amazing Grace\n").unwrap();
+        let sorted: SlideContent =
+            serde_yaml::from_str("title_text: Amazing Grace\ntype: Title\n").unwrap();
+
+        assert_eq!(tagged, expected);
+        assert_eq!(nested, expected);
+        assert_eq!(sorted, expected);
+        assert!(serde_yaml::from_str::<SlideContent>("!Unknown\ntitle_text: x\n").is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+use unicode_normalization::UnicodeNormalization;
+
+impl<T, M> PresentationChapter<T, M>
+where
+    T: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+    M: Clone + Serialize + for<'de> Deserialize<'de> + PartialEq + Debug,
+{
+    /// Converts all text of the chapter to the Unicode normalization form C (NFC),
+    /// so that precomposed and decomposed characters compare equal afterwards.
+    /// Like [`PresentationChapter::normalize_text`], this includes notes, section labels,
+    /// alternative texts of pictures and the title of a linked title entity.
+    pub fn normalize_unicode_nfc(&mut self) {
+        for text in self.all_text_fields_mut() {
+            *text = text.nfc().collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_unicode_nfc() {
+        // "é" as "e" followed by a combining acute accent
+        let decomposed = "Caf\u{0065}\u{0301}";
+        let precomposed = "Caf\u{00e9}";
+        assert_ne!(decomposed, precomposed);
+
+        let mut chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(decomposed.to_string(), Some(decomposed.to_string())),
+                Slide::new_content_slide(decomposed.to_string(), None, None)
+                    .with_notes(decomposed.to_string()),
+                Slide::from_content(SlideContent::SingleLanguageMainContent(
+                    SingleLanguageMainContentSlide {
+                        section_label: Some(decomposed.to_string()),
+                        ..Default::default()
+                    },
+                )),
+                Slide::from_content(SlideContent::SimplePicture(
+                    SimplePictureSlide::new("cafe.png".to_string())
+                        .with_alt_text(decomposed.to_string()),
+                )),
+            ],
+            LinkedEntity::Title(decomposed.to_string()),
+        );
+
+        chapter.normalize_unicode_nfc();
+
+        assert_eq!(
+            chapter.linked_entity,
+            LinkedEntity::Title(precomposed.to_string())
+        );
+        assert_eq!(chapter[1].notes.as_deref(), Some(precomposed));
+        let SlideContent::SingleLanguageMainContent(section) = &chapter[2].slide_content else {
+            panic!("The section slide is a content slide");
+        };
+        assert_eq!(section.section_label.as_deref(), Some(precomposed));
+        let SlideContent::SimplePicture(picture) = &chapter[3].slide_content else {
+            panic!("The picture slide is a picture slide");
+        };
+        assert_eq!(picture.alt_text.as_deref(), Some(precomposed));
+
+        assert_eq!(
+            chapter[0].slide_content.all_text(),
+            vec![precomposed, precomposed]
+        );
+        assert_eq!(chapter[1].to_string(), precomposed);
+        assert_eq!(chapter.find_slides_containing(precomposed), vec![0, 1]);
+    }
+}
@@ -0,0 +1,51 @@
+use super::*;
+
+/// Serializes a presentation chapter to a YAML string
+pub fn to_yaml_string<T, M>(
+    chapter: &PresentationChapter<T, M>,
+) -> Result<String, serde_yaml::Error>
+where
+    T: Serialize,
+    M: Serialize,
+{
+    serde_yaml::to_string(chapter)
+}
+
+/// Deserializes a presentation chapter from a YAML string
+pub fn from_yaml_str<T, M>(yaml: &str) -> Result<PresentationChapter<T, M>, serde_yaml::Error>
+where
+    T: for<'de> Deserialize<'de>,
+    M: for<'de> Deserialize<'de>,
+{
+    serde_yaml::from_str(yaml)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yaml_roundtrip() {
+        let chapter = PresentationChapter::<String, String>::new(
+            vec![
+                Slide::new_title_slide(
+                    "Amazing Grace".to_string(),
+                    Some("John Newton".to_string()),
+                ),
+                Slide::new_content_slide(
+                    "Amazing grace, how sweet the sound\nThat saved a wretch like me".to_string(),
+                    None,
+                    Some("Verse 1".to_string()),
+                )
+                .with_media("grace.mp3".to_string()),
+                Slide::new_empty_slide(true),
+            ],
+            LinkedEntity::Source("amazing-grace".to_string()),
+        );
+
+        let yaml = to_yaml_string(&chapter).unwrap();
+        let deserialized: PresentationChapter<String, String> = from_yaml_str(&yaml).unwrap();
+
+        assert_eq!(chapter, deserialized);
+    }
+}